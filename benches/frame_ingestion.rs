@@ -0,0 +1,112 @@
+//! Compares the allocations needed to split the binary websocket messages of one galaxy tick into
+//! packets, once with the former copying ingestion and once with the zero-copy ingestion of
+//! [`MultiPacketBuffer`].
+//!
+//! Run with `cargo bench --bench frame_ingestion --features bench-internals`.
+
+use bytes::BytesMut;
+use flattiverse_connector::network::bench::MultiPacketBuffer;
+use flattiverse_connector::network::PacketHeader;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Unit updates in a busy cluster during one tick.
+const PACKETS_PER_TICK: usize = 600;
+/// Payload of a typical movement update: cluster, name and a handful of floats.
+const PAYLOAD_SIZE: usize = 48;
+/// Packets the server bundles into a single websocket message.
+const PACKETS_PER_MESSAGE: usize = 20;
+const TICKS: usize = 1_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn message() -> Vec<u8> {
+    let mut message = Vec::with_capacity(PACKETS_PER_MESSAGE * (PacketHeader::SIZE + PAYLOAD_SIZE));
+    for _ in 0..PACKETS_PER_MESSAGE {
+        message.extend_from_slice(&[0x31, 0x00]);
+        message.extend_from_slice(&(PAYLOAD_SIZE as u16).to_le_bytes());
+        message.extend((0..PAYLOAD_SIZE).map(|i| i as u8));
+    }
+    message
+}
+
+/// Runs `ingest` for every message of every tick and returns the average allocations, allocated
+/// bytes and duration per tick. Creating the messages, which the websocket does in any case, is
+/// not measured.
+fn measure(ingest: impl Fn(Vec<u8>) -> MultiPacketBuffer) -> (usize, usize, Duration) {
+    let template = message();
+    let mut allocations = 0;
+    let mut allocated_bytes = 0;
+    let mut duration = Duration::ZERO;
+
+    for _ in 0..TICKS {
+        let messages = (0..PACKETS_PER_TICK / PACKETS_PER_MESSAGE)
+            .map(|_| template.clone())
+            .collect::<Vec<_>>();
+
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated_bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let start = Instant::now();
+
+        for message in messages {
+            let mut buffer = ingest(message);
+            while let Some(packet) = buffer.next_packet() {
+                black_box(packet);
+            }
+        }
+
+        duration += start.elapsed();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        allocated_bytes += ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes_before;
+    }
+
+    (
+        allocations / TICKS,
+        allocated_bytes / TICKS,
+        duration / TICKS as u32,
+    )
+}
+
+fn main() {
+    println!(
+        "{PACKETS_PER_TICK} packets per tick, {PACKETS_PER_MESSAGE} packets per message, {PAYLOAD_SIZE} bytes payload, averaged over {TICKS} ticks"
+    );
+
+    for (name, (allocations, allocated_bytes, duration)) in [
+        (
+            "copy",
+            measure(|message| MultiPacketBuffer::from(BytesMut::from(&message[..]))),
+        ),
+        ("zero-copy", measure(MultiPacketBuffer::from)),
+    ] {
+        println!(
+            "{name:>10}: {allocations:>5} allocations, {allocated_bytes:>7} bytes, {duration:>10?} per tick"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Compares the proximity queries and the ray casts of [`SpatialGrid`], which backs the queries of a
+//! [`flattiverse_connector::galaxy_hierarchy::Cluster`], with a linear scan over all units like
+//! iterating `Cluster::iter_units` does.
+//!
+//! Run with `cargo bench --bench spatial_index --features bench-internals`.
+
+use flattiverse_connector::network::bench::SpatialGrid;
+use flattiverse_connector::Vector;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Units in a busy cluster.
+const UNITS: usize = 2_000;
+/// Width and height of the populated area.
+const EXTENT: f32 = 20_000.0;
+/// Roughly the scan range of a ship.
+const QUERY_RADIUS: f32 = 400.0;
+const K: usize = 8;
+/// Roughly the range of a railgun.
+const RAY_LENGTH: f32 = 3_000.0;
+const QUERIES: usize = 10_000;
+
+struct Unit {
+    name: String,
+    position: Vector,
+    radius: f32,
+}
+
+/// Deterministic pseudo random numbers in `0.0..1.0`, so all runs query the same layout.
+fn random(seed: &mut u64) -> f32 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    (*seed >> 40) as f32 / (1_u64 << 24) as f32
+}
+
+fn random_position(seed: &mut u64) -> Vector {
+    Vector::new((random(seed) - 0.5) * EXTENT, (random(seed) - 0.5) * EXTENT)
+}
+
+fn units(seed: &mut u64) -> Vec<Unit> {
+    (0..UNITS)
+        .map(|i| Unit {
+            name: format!("unit-{i}"),
+            position: random_position(seed),
+            // mostly ships and shots, some suns and planets
+            radius: if i % 50 == 0 {
+                200.0 + random(seed) * 800.0
+            } else {
+                3.0 + random(seed) * 20.0
+            },
+        })
+        .collect()
+}
+
+fn distance(unit: &Unit, position: Vector) -> f32 {
+    ((unit.position - position).length() - unit.radius).max(0.0)
+}
+
+/// The distance at which the ray hits the unit, `0.0` if the origin is inside.
+fn hit(unit: &Unit, origin: Vector, direction: Vector) -> Option<f32> {
+    let offset = origin - unit.position;
+    let c = offset.length_squared() - unit.radius * unit.radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let b = offset.x * direction.x + offset.y * direction.y;
+    let discriminant = b * b - c;
+    (b <= 0.0 && discriminant >= 0.0).then(|| -b - discriminant.sqrt())
+}
+
+/// Some direction derived from the position, so every query points elsewhere.
+fn direction(position: Vector) -> Vector {
+    Vector::from_angle_length(position.x + position.y, 1.0)
+}
+
+fn measure(queries: &[Vector], mut query: impl FnMut(Vector) -> usize) -> (Duration, usize) {
+    let start = Instant::now();
+    let found = queries.iter().map(|position| query(*position)).sum();
+    (start.elapsed() / queries.len() as u32, found)
+}
+
+fn main() {
+    let mut seed = 0x2545_F491_4F6C_DD1D;
+    let units = units(&mut seed);
+    let queries = (0..QUERIES)
+        .map(|_| random_position(&mut seed))
+        .collect::<Vec<_>>();
+
+    let mut grid = SpatialGrid::default();
+    for (index, unit) in units.iter().enumerate() {
+        grid.insert(&unit.name, unit.position, unit.radius, index);
+    }
+
+    println!(
+        "{UNITS} units in {EXTENT}x{EXTENT}, query radius {QUERY_RADIUS}, k={K}, ray length {RAY_LENGTH}, averaged over {QUERIES} queries"
+    );
+
+    let within_scan = measure(&queries, |position| {
+        units
+            .iter()
+            .filter(|unit| distance(unit, position) <= QUERY_RADIUS)
+            .map(black_box)
+            .count()
+    });
+    let within_grid = measure(&queries, |position| {
+        black_box(grid.within(position, QUERY_RADIUS)).len()
+    });
+
+    let nearest_scan = measure(&queries, |position| {
+        let mut nearest = units
+            .iter()
+            .map(|unit| (unit, distance(unit, position)))
+            .collect::<Vec<_>>();
+        nearest.sort_by(|a, b| a.1.total_cmp(&b.1));
+        nearest.truncate(K);
+        black_box(nearest).len()
+    });
+    let nearest_grid = measure(&queries, |position| {
+        black_box(grid.k_nearest(position, K, |_| true)).len()
+    });
+
+    // sums up the indices of the hit units to compare the results
+    let raycast_scan = measure(&queries, |position| {
+        let direction = direction(position);
+        units
+            .iter()
+            .enumerate()
+            .filter_map(|(index, unit)| Some((index, hit(unit, position, direction)?)))
+            .filter(|(_, distance)| *distance <= RAY_LENGTH)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(0, |(index, _)| black_box(index) + 1)
+    });
+    let raycast_grid = measure(&queries, |position| {
+        grid.raycast(position, direction(position), RAY_LENGTH, |_| true)
+            .map_or(0, |(index, _)| black_box(*index) + 1)
+    });
+
+    for (name, (scan, scan_found), (indexed, indexed_found)) in [
+        ("within", within_scan, within_grid),
+        ("k_nearest", nearest_scan, nearest_grid),
+        ("raycast", raycast_scan, raycast_grid),
+    ] {
+        assert_eq!(scan_found, indexed_found, "{name} found different units");
+        println!("{name:>10}: scan {scan:>10?}, grid {indexed:>10?} per query");
+    }
+}
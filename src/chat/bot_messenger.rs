@@ -0,0 +1,510 @@
+use crate::galaxy_hierarchy::{Player, PlayerId};
+use crate::utils::MAX_BINARY_MESSAGE_LEN;
+use crate::{FlattiverseEvent, FlattiverseEventKind, GameError, GameErrorKind};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+/// A message which can be exchanged between bots through a [`BotMessenger`].
+///
+/// The type tag identifies the message on the receiving side, so it has to be unique among the
+/// messages of the bots talking to each other.
+pub trait BotMessage: Serialize + DeserializeOwned {
+    const TYPE: &'static str;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BotMessageError {
+    #[error("Failed to send the message: {0}")]
+    Game(#[from] GameError),
+    #[error("Failed to encode or decode the message: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("The message is too large with {0} bytes.")]
+    TooLarge(usize),
+    #[error("Expected a message of type {expected:?}, but got {actual:?}.")]
+    UnexpectedType {
+        expected: &'static str,
+        actual: String,
+    },
+    #[error("The request has been dropped without a response.")]
+    NoResponse,
+    #[error("No response arrived within {0:?}.")]
+    Timeout(Duration),
+}
+
+/// A complete message received from another bot, see [`BotMessenger::handle`].
+#[derive(Debug, Clone)]
+pub struct ReceivedBotMessage {
+    sender: Arc<Player>,
+    id: u32,
+    envelope: Envelope<serde_json::Value>,
+}
+
+impl ReceivedBotMessage {
+    #[inline]
+    pub fn sender(&self) -> &Arc<Player> {
+        &self.sender
+    }
+
+    /// The id the sender assigned to this message, unique per sender.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The type tag of the message, see [`BotMessage::TYPE`].
+    #[inline]
+    pub fn type_tag(&self) -> &str {
+        &self.envelope.type_tag
+    }
+
+    /// Whether the sender awaits a response, see [`BotMessenger::respond`].
+    #[inline]
+    pub fn is_request(&self) -> bool {
+        self.envelope.request
+    }
+
+    /// Whether the message is of the given type.
+    #[inline]
+    pub fn is<M: BotMessage>(&self) -> bool {
+        self.envelope.type_tag == M::TYPE
+    }
+
+    /// Decodes the message, which fails if it is of another type.
+    pub fn decode<M: BotMessage>(&self) -> Result<M, BotMessageError> {
+        self.envelope.decode()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Envelope<T> {
+    #[serde(rename = "t")]
+    type_tag: String,
+    #[serde(rename = "q", default, skip_serializing_if = "std::ops::Not::not")]
+    request: bool,
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    reply_to: Option<u32>,
+    #[serde(rename = "p")]
+    payload: T,
+}
+
+impl Envelope<serde_json::Value> {
+    fn decode<M: BotMessage>(&self) -> Result<M, BotMessageError> {
+        if self.type_tag == M::TYPE {
+            Ok(M::deserialize(&self.payload)?)
+        } else {
+            Err(BotMessageError::UnexpectedType {
+                expected: M::TYPE,
+                actual: self.type_tag.clone(),
+            })
+        }
+    }
+}
+
+/// Sending state of the binary channel to one player.
+#[derive(Debug, Default)]
+struct Peer {
+    /// Whether the first binary message has been sent, which opens the channel.
+    opened: bool,
+    /// Whether the player has replied with a binary message, which allows further messages.
+    acknowledged: bool,
+    /// Frames waiting for the acknowledgement.
+    queue: VecDeque<Vec<u8>>,
+}
+
+type PendingRequest = oneshot::Sender<Envelope<serde_json::Value>>;
+
+#[derive(Debug)]
+struct Partial {
+    frames: Vec<Option<Vec<u8>>>,
+    missing: usize,
+    /// The bytes of the received frames.
+    len: usize,
+}
+
+/// Typed messaging between bots on top of the private binary chat.
+///
+/// Messages are encoded as JSON together with their [`BotMessage::TYPE`] and split into as many
+/// binary chat messages as needed. The server only accepts further binary messages to a player
+/// after that player has replied with a binary message. The messenger therefore acknowledges the
+/// first message of every player automatically and holds back further frames to a player until
+/// its acknowledgement has arrived, so both sides have to use a messenger.
+///
+/// Every [`FlattiverseEventKind::PlayerBinaryChat`] has to be passed to
+/// [`BotMessenger::handle`], which returns the completely received messages. Only available with
+/// the `json` feature.
+#[derive(Debug)]
+pub struct BotMessenger {
+    next_id: AtomicU32,
+    request_timeout: Duration,
+    peers: Mutex<BTreeMap<PlayerId, Peer>>,
+    partials: Mutex<BTreeMap<(PlayerId, u32), Partial>>,
+    requests: Mutex<BTreeMap<(PlayerId, u32), PendingRequest>>,
+}
+
+impl Default for BotMessenger {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            next_id: AtomicU32::default(),
+            request_timeout: Self::DEFAULT_REQUEST_TIMEOUT,
+            peers: Mutex::default(),
+            partials: Mutex::default(),
+            requests: Mutex::default(),
+        }
+    }
+}
+
+impl BotMessenger {
+    /// The maximum length of one frame, which is sent as one binary chat message.
+    pub const MAX_FRAME_LEN: usize = MAX_BINARY_MESSAGE_LEN;
+    /// The maximum length of an encoded message. Larger messages are rejected when sending and
+    /// when receiving their first frame.
+    pub const MAX_ENCODED_LEN: usize = 256 * Self::CHUNK_LEN;
+    /// Partially received messages which are kept at most per sender. If more are pending, for
+    /// example because the sender restarted, those of the sender are discarded.
+    pub const MAX_PARTIAL_MESSAGES: usize = 16;
+    /// Bytes of partially received messages which are kept at most per sender, exceeding them
+    /// discards the messages of the sender like [`BotMessenger::MAX_PARTIAL_MESSAGES`].
+    pub const MAX_PARTIAL_LEN: usize = 4 * Self::MAX_ENCODED_LEN;
+    /// How long [`BotMessenger::request`] waits for the response, see
+    /// [`BotMessenger::with_request_timeout`].
+    pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+    const MAGIC: u8 = 0xB7;
+    const KIND_DATA: u8 = 0;
+    const KIND_ACK: u8 = 1;
+    /// magic, kind, id, index, count
+    const HEADER_LEN: usize = 1 + 1 + 4 + 2 + 2;
+    /// The bytes of the encoded message carried by one frame.
+    const CHUNK_LEN: usize = Self::MAX_FRAME_LEN - Self::HEADER_LEN;
+    const MAX_FRAMES: usize = Self::MAX_ENCODED_LEN.div_ceil(Self::CHUNK_LEN);
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long [`BotMessenger::request`] waits for the response before it fails with
+    /// [`BotMessageError::Timeout`].
+    #[inline]
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    #[inline]
+    pub fn request_timeout(&self) -> Duration {
+        self.request_timeout
+    }
+
+    /// Sends the message to the player.
+    pub async fn send<M: BotMessage>(
+        &self,
+        player: &Player,
+        message: &M,
+    ) -> Result<(), BotMessageError> {
+        self.send_envelope(player, self.next_id(), message, false, None)
+            .await
+    }
+
+    /// Sends the message to the player and awaits its response, see [`BotMessenger::respond`].
+    /// The response is delivered through [`BotMessenger::handle`], so events have to keep being
+    /// passed to it meanwhile, usually from another task. Fails with [`BotMessageError::Timeout`]
+    /// if the response did not arrive within the [`BotMessenger::request_timeout`].
+    ///
+    /// On wasm there is no timer, so the request waits until the response arrives or the player
+    /// parts.
+    pub async fn request<Req: BotMessage, Resp: BotMessage>(
+        &self,
+        player: &Player,
+        request: &Req,
+    ) -> Result<Resp, BotMessageError> {
+        let id = self.next_id();
+        let key = (player.id(), id);
+        let (sender, receiver) = oneshot::channel();
+        self.requests.lock().unwrap().insert(key, sender);
+
+        let result = match self.send_envelope(player, id, request, true, None).await {
+            Ok(()) => self.response(receiver).await,
+            Err(e) => Err(e),
+        };
+
+        self.requests.lock().unwrap().remove(&key);
+        result?.decode()
+    }
+
+    /// Sends the response to a request received through [`BotMessenger::handle`].
+    pub async fn respond<M: BotMessage>(
+        &self,
+        request: &ReceivedBotMessage,
+        response: &M,
+    ) -> Result<(), BotMessageError> {
+        self.send_envelope(
+            &request.sender,
+            self.next_id(),
+            response,
+            false,
+            Some(request.id),
+        )
+        .await
+    }
+
+    /// Processes an event of the galaxy and returns the message it completes, if any. Responses
+    /// to [`BotMessenger::request`]s are delivered to the request instead.
+    pub async fn handle(
+        &self,
+        event: &FlattiverseEvent,
+    ) -> Result<Option<ReceivedBotMessage>, BotMessageError> {
+        match event.kind() {
+            FlattiverseEventKind::PlayerBinaryChat {
+                player, message, ..
+            } => self.receive(player, message).await,
+            FlattiverseEventKind::PlayerJoined { player }
+            | FlattiverseEventKind::PlayerParted { player } => {
+                // the id might be reused by another player
+                self.forget(player.id());
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    #[cfg(not(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    )))]
+    async fn response(
+        &self,
+        receiver: oneshot::Receiver<Envelope<serde_json::Value>>,
+    ) -> Result<Envelope<serde_json::Value>, BotMessageError> {
+        match tokio::time::timeout(self.request_timeout, receiver).await {
+            Ok(response) => response.map_err(|_| BotMessageError::NoResponse),
+            Err(_) => Err(BotMessageError::Timeout(self.request_timeout)),
+        }
+    }
+
+    #[cfg(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    ))]
+    async fn response(
+        &self,
+        receiver: oneshot::Receiver<Envelope<serde_json::Value>>,
+    ) -> Result<Envelope<serde_json::Value>, BotMessageError> {
+        receiver.await.map_err(|_| BotMessageError::NoResponse)
+    }
+
+    #[inline]
+    fn next_id(&self) -> u32 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn forget(&self, player: PlayerId) {
+        self.peers.lock().unwrap().remove(&player);
+        self.partials
+            .lock()
+            .unwrap()
+            .retain(|(sender, _), _| *sender != player);
+        self.requests
+            .lock()
+            .unwrap()
+            .retain(|(sender, _), _| *sender != player);
+    }
+
+    async fn send_envelope<M: BotMessage>(
+        &self,
+        player: &Player,
+        id: u32,
+        message: &M,
+        request: bool,
+        reply_to: Option<u32>,
+    ) -> Result<(), BotMessageError> {
+        let encoded = serde_json::to_vec(&Envelope {
+            type_tag: M::TYPE.to_string(),
+            request,
+            reply_to,
+            payload: message,
+        })?;
+
+        if encoded.len() > Self::MAX_ENCODED_LEN {
+            return Err(BotMessageError::TooLarge(encoded.len()));
+        }
+        let count = encoded.len().div_ceil(Self::CHUNK_LEN) as u16;
+
+        let frames = encoded
+            .chunks(Self::CHUNK_LEN)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut frame = Vec::with_capacity(Self::HEADER_LEN + chunk.len());
+                frame.extend_from_slice(&[Self::MAGIC, Self::KIND_DATA]);
+                frame.extend_from_slice(&id.to_le_bytes());
+                frame.extend_from_slice(&(index as u16).to_le_bytes());
+                frame.extend_from_slice(&count.to_le_bytes());
+                frame.extend_from_slice(chunk);
+                frame
+            })
+            .collect::<Vec<_>>();
+
+        Ok(self.send_frames(player, frames).await?)
+    }
+
+    /// Sends the frames as far as the binary channel allows and queues the rest until the player
+    /// acknowledged the channel.
+    async fn send_frames(&self, player: &Player, frames: Vec<Vec<u8>>) -> Result<(), GameError> {
+        let (first, bulk) = {
+            let mut peers = self.peers.lock().unwrap();
+            let peer = peers.entry(player.id()).or_default();
+
+            if peer.acknowledged && peer.queue.is_empty() {
+                (None, frames)
+            } else if !peer.opened {
+                let mut frames = frames.into_iter();
+                peer.opened = true;
+                peer.queue.extend(frames.by_ref().skip(1));
+                (frames.next(), Vec::new())
+            } else {
+                peer.queue.extend(frames);
+                (None, Vec::new())
+            }
+        };
+
+        if let Some(first) = first {
+            player.chat_binary(first).await?;
+        }
+
+        self.send_bulk(player, bulk).await
+    }
+
+    async fn send_bulk(&self, player: &Player, frames: Vec<Vec<u8>>) -> Result<(), GameError> {
+        let mut frames = frames.into_iter().peekable();
+        while frames.peek().is_some() {
+            let batch = frames.by_ref().take(32).collect::<Vec<_>>();
+            if let Err(e) = player.chat_binary32(batch.clone()).await {
+                if matches!(e.kind(), GameErrorKind::BinaryChatAckRequired) {
+                    // the server does not consider the channel acknowledged (anymore)
+                    let mut peers = self.peers.lock().unwrap();
+                    let peer = peers.entry(player.id()).or_default();
+                    peer.acknowledged = false;
+                    peer.opened = true;
+                    for frame in batch.into_iter().chain(frames).rev() {
+                        peer.queue.push_front(frame);
+                    }
+                    return Ok(());
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn receive(
+        &self,
+        player: &Arc<Player>,
+        frame: &[u8],
+    ) -> Result<Option<ReceivedBotMessage>, BotMessageError> {
+        if frame.len() < 2 || frame[0] != Self::MAGIC {
+            // not for us
+            return Ok(None);
+        }
+
+        // any binary message acknowledges the channel
+        let (ack, queued) = {
+            let mut peers = self.peers.lock().unwrap();
+            let peer = peers.entry(player.id()).or_default();
+            peer.acknowledged = true;
+            let ack = !peer.opened;
+            peer.opened = true;
+            (ack, peer.queue.drain(..).collect::<Vec<_>>())
+        };
+
+        if ack {
+            player.chat_binary([Self::MAGIC, Self::KIND_ACK]).await?;
+        }
+        self.send_bulk(player, queued).await?;
+
+        if frame[1] != Self::KIND_DATA || frame.len() < Self::HEADER_LEN {
+            return Ok(None);
+        }
+
+        let id = u32::from_le_bytes([frame[2], frame[3], frame[4], frame[5]]);
+        let index = usize::from(u16::from_le_bytes([frame[6], frame[7]]));
+        let count = usize::from(u16::from_le_bytes([frame[8], frame[9]]));
+        let chunk = &frame[Self::HEADER_LEN..];
+
+        if index >= count || count > Self::MAX_FRAMES || chunk.len() > Self::CHUNK_LEN {
+            warn!("Discarding bot message frame {index} of {count} from {player:?}");
+            return Ok(None);
+        }
+
+        let encoded = if count == 1 {
+            chunk.to_vec()
+        } else {
+            let mut partials = self.partials.lock().unwrap();
+            let key = (player.id(), id);
+            let partial = partials.entry(key).or_insert_with(|| Partial {
+                frames: vec![None; count],
+                missing: count,
+                len: 0,
+            });
+
+            if partial.frames.len() != count {
+                warn!("Discarding bot message {id} from {player:?} with inconsistent frames");
+                partials.remove(&key);
+                return Ok(None);
+            }
+
+            partial.len += chunk.len();
+            match partial.frames[index].replace(chunk.to_vec()) {
+                Some(previous) => partial.len -= previous.len(),
+                None => partial.missing -= 1,
+            }
+
+            if partial.missing > 0 {
+                let sender = (player.id(), 0)..=(player.id(), u32::MAX);
+                let (messages, len) = partials
+                    .range(sender.clone())
+                    .fold((0, 0), |(messages, len), (_, partial)| {
+                        (messages + 1, len + partial.len)
+                    });
+                if messages > Self::MAX_PARTIAL_MESSAGES || len > Self::MAX_PARTIAL_LEN {
+                    warn!("Too many incomplete bot messages from {player:?}, discarding them");
+                    partials.retain(|key, _| !sender.contains(key));
+                }
+                return Ok(None);
+            }
+
+            partials
+                .remove(&key)
+                .into_iter()
+                .flat_map(|partial| partial.frames)
+                .flatten()
+                .flatten()
+                .collect()
+        };
+
+        let envelope = serde_json::from_slice::<Envelope<serde_json::Value>>(&encoded)?;
+
+        if let Some(reply_to) = envelope.reply_to {
+            let request = self
+                .requests
+                .lock()
+                .unwrap()
+                .remove(&(player.id(), reply_to));
+            if let Some(request) = request {
+                let _ = request.send(envelope);
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(ReceivedBotMessage {
+            sender: Arc::clone(player),
+            id,
+            envelope,
+        }))
+    }
+}
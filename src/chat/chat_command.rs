@@ -0,0 +1,286 @@
+use crate::galaxy_hierarchy::{Galaxy, Player};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The chat a command has been received in. Replies are sent to the same chat.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChatChannel {
+    /// [`crate::FlattiverseEventKind::GalaxyChat`]
+    Galaxy,
+    /// [`crate::FlattiverseEventKind::TeamChat`]
+    Team,
+    /// [`crate::FlattiverseEventKind::PlayerChat`], replies are sent privately to the sender.
+    Private,
+}
+
+/// Who may invoke a [`ChatCommand`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatPermission {
+    Everyone,
+    /// Players of your own team.
+    Team,
+    /// Players with the admin flag, see [`Player::admin`].
+    Admin,
+    /// Players with one of the given names. Names are used as player ids are only valid for one
+    /// session.
+    Players(Vec<String>),
+    /// Granted if any of the given permissions is granted.
+    AnyOf(Vec<ChatPermission>),
+}
+
+impl ChatPermission {
+    /// Whether the given player of the galaxy is allowed to invoke the command.
+    pub fn allows(&self, galaxy: &Galaxy, player: &Player) -> bool {
+        match self {
+            Self::Everyone => true,
+            Self::Team => player
+                .team_weak()
+                .upgrade()
+                .is_some_and(|team| team.id() == galaxy.player().team().id()),
+            Self::Admin => player.admin(),
+            Self::Players(names) => names.iter().any(|name| name == player.name()),
+            Self::AnyOf(permissions) => permissions.iter().any(|p| p.allows(galaxy, player)),
+        }
+    }
+}
+
+/// The arguments following the name of a command, separated by whitespace. An argument containing
+/// whitespace can be enclosed in double quotes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChatArgs {
+    raw: String,
+    args: Vec<String>,
+    /// Byte offset of each argument in `raw`, for [`ChatArgs::rest`].
+    offsets: Vec<usize>,
+}
+
+impl ChatArgs {
+    pub fn parse(raw: &str) -> Self {
+        let mut args = Vec::new();
+        let mut offsets = Vec::new();
+        let mut chars = raw.char_indices().peekable();
+
+        while let Some((offset, c)) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            let mut arg = String::new();
+            if c == '"' {
+                for (_, c) in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    arg.push(c);
+                }
+            } else {
+                arg.push(c);
+                while let Some((_, c)) = chars.next_if(|(_, c)| !c.is_whitespace()) {
+                    arg.push(c);
+                }
+            }
+
+            args.push(arg);
+            offsets.push(offset);
+        }
+
+        Self {
+            raw: raw.to_string(),
+            args,
+            offsets,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// The argument at the given index, or [`ChatCommandError::MissingArgument`].
+    #[inline]
+    pub fn required(&self, index: usize) -> Result<&str, ChatCommandError> {
+        self.get(index)
+            .ok_or(ChatCommandError::MissingArgument { index })
+    }
+
+    /// Parses the argument at the given index.
+    pub fn parse_arg<T: FromStr>(&self, index: usize) -> Result<T, ChatCommandError> {
+        let value = self.required(index)?;
+        value
+            .parse()
+            .map_err(|_| ChatCommandError::InvalidArgument {
+                index,
+                value: value.to_string(),
+            })
+    }
+
+    /// Parses the argument at the given index if it is present.
+    pub fn parse_opt<T: FromStr>(&self, index: usize) -> Result<Option<T>, ChatCommandError> {
+        match self.get(index) {
+            Some(_) => self.parse_arg(index).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The unparsed text from the argument at the given index on, for free text like messages.
+    #[inline]
+    pub fn rest(&self, index: usize) -> Option<&str> {
+        self.offsets
+            .get(index)
+            .map(|offset| self.raw[*offset..].trim_end())
+    }
+
+    /// The unparsed text of all arguments.
+    #[inline]
+    pub fn raw(&self) -> &str {
+        self.raw.trim()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.args.iter().map(String::as_str)
+    }
+}
+
+/// Failure of a command, replied to the sender as text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ChatCommandError {
+    #[error("Unknown command {0:?}.")]
+    UnknownCommand(String),
+    #[error("You are not allowed to use this command.")]
+    PermissionDenied,
+    #[error("Argument {} is missing.", index + 1)]
+    MissingArgument { index: usize },
+    #[error("Argument {} is invalid: {value:?}.", index + 1)]
+    InvalidArgument { index: usize, value: String },
+    #[error("{0}")]
+    Failed(String),
+}
+
+/// One received command, passed to the handler of a [`ChatCommand`].
+#[derive(Debug, Clone)]
+pub struct ChatInvocation {
+    pub galaxy: Arc<Galaxy>,
+    pub sender: Arc<Player>,
+    pub channel: ChatChannel,
+    /// The name of the command as written by the sender, which might be an alias.
+    pub command: String,
+    pub args: ChatArgs,
+}
+
+/// The result of a command: the text to reply with, if any.
+pub type ChatCommandResult = Result<Option<String>, ChatCommandError>;
+
+type ChatCommandFuture = Pin<Box<dyn Future<Output = ChatCommandResult> + Send>>;
+type ChatCommandHandler = dyn Fn(ChatInvocation) -> ChatCommandFuture + Send + Sync;
+
+/// A command of a [`crate::chat::ChatCommandRouter`].
+#[derive(Clone)]
+pub struct ChatCommand {
+    name: String,
+    aliases: Vec<String>,
+    usage: String,
+    description: String,
+    permission: ChatPermission,
+    handler: Arc<ChatCommandHandler>,
+}
+
+impl Debug for ChatCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChatCommand")
+            .field("name", &self.name)
+            .field("aliases", &self.aliases)
+            .field("usage", &self.usage)
+            .field("description", &self.description)
+            .field("permission", &self.permission)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChatCommand {
+    /// Creates a command everyone may invoke. The handler is awaited for every invocation and its
+    /// result is replied to the sender.
+    pub fn new<F, Fut>(name: impl Into<String>, description: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(ChatInvocation) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ChatCommandResult> + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            aliases: Vec::new(),
+            usage: String::new(),
+            description: description.into(),
+            permission: ChatPermission::Everyone,
+            handler: Arc::new(move |invocation| Box::pin(handler(invocation))),
+        }
+    }
+
+    /// Another name the command can be invoked with.
+    #[inline]
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Describes the arguments in the help, like `<x> <y> [speed]`.
+    #[inline]
+    pub fn with_usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = usage.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_permission(mut self, permission: ChatPermission) -> Self {
+        self.permission = permission;
+        self
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    #[inline]
+    pub fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    #[inline]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[inline]
+    pub fn permission(&self) -> &ChatPermission {
+        &self.permission
+    }
+
+    /// Whether the command is invoked by the given name, case-insensitive.
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+    }
+
+    #[inline]
+    pub(crate) fn invoke(&self, invocation: ChatInvocation) -> ChatCommandFuture {
+        (self.handler)(invocation)
+    }
+}
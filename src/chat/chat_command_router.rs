@@ -0,0 +1,226 @@
+use crate::chat::{ChatArgs, ChatChannel, ChatCommand, ChatCommandError, ChatInvocation};
+use crate::galaxy_hierarchy::{Galaxy, Player};
+use crate::utils::split_message;
+use crate::{FlattiverseEvent, FlattiverseEventKind, GameError};
+use std::sync::Arc;
+
+/// Dispatches chat messages starting with a prefix, like `!goto 100 200`, to [`ChatCommand`]s and
+/// replies with their results.
+///
+/// The router is fed with the events of the galaxy through [`ChatCommandRouter::handle`], usually
+/// from [`crate::EventHandler::on_chat`]. Commands are accepted from galaxy, team and private chat
+/// and replies are sent to the chat the command came from, split into as many messages as needed
+/// to pass [`crate::utils::check_message`]. Own messages are ignored.
+#[derive(Debug, Clone)]
+pub struct ChatCommandRouter {
+    prefix: String,
+    commands: Vec<ChatCommand>,
+    help: bool,
+    reply_unknown: bool,
+}
+
+impl Default for ChatCommandRouter {
+    #[inline]
+    fn default() -> Self {
+        Self::new("!")
+    }
+}
+
+impl ChatCommandRouter {
+    /// Creates a router for messages starting with the given prefix. The `help` command is
+    /// available by default.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            commands: Vec::new(),
+            help: true,
+            reply_unknown: true,
+        }
+    }
+
+    /// Adds a command. If several commands share a name, the first one added wins.
+    #[inline]
+    pub fn with_command(mut self, command: ChatCommand) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Whether the generated `help` command is available. It lists the commands the sender is
+    /// allowed to use, or describes a single one with `help <command>`. A command named `help`
+    /// replaces it.
+    #[inline]
+    pub fn with_help(mut self, help: bool) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// Whether unknown commands are answered with an error. Turn this off if several bots share
+    /// a prefix.
+    #[inline]
+    pub fn with_unknown_command_replies(mut self, reply_unknown: bool) -> Self {
+        self.reply_unknown = reply_unknown;
+        self
+    }
+
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    #[inline]
+    pub fn commands(&self) -> &[ChatCommand] {
+        &self.commands
+    }
+
+    /// Parses a chat event into an invocation, if it is a command for this router.
+    pub fn parse(&self, galaxy: &Arc<Galaxy>, event: &FlattiverseEvent) -> Option<ChatInvocation> {
+        let (player, channel, message) = match event.kind() {
+            FlattiverseEventKind::GalaxyChat {
+                player, message, ..
+            } => (player, ChatChannel::Galaxy, message),
+            FlattiverseEventKind::TeamChat {
+                player, message, ..
+            } => (player, ChatChannel::Team, message),
+            FlattiverseEventKind::PlayerChat {
+                player, message, ..
+            } => (player, ChatChannel::Private, message),
+            _ => return None,
+        };
+
+        if player.id() == galaxy.player().id() {
+            return None;
+        }
+
+        let text = message.trim_start().strip_prefix(&self.prefix)?;
+        let name_len = text.find(char::is_whitespace).unwrap_or(text.len());
+        if name_len == 0 {
+            return None;
+        }
+
+        Some(ChatInvocation {
+            galaxy: Arc::clone(galaxy),
+            sender: Arc::clone(player),
+            channel,
+            command: text[..name_len].to_string(),
+            args: ChatArgs::parse(&text[name_len..]),
+        })
+    }
+
+    /// Executes the command of a chat event and sends the reply. Returns whether the event was a
+    /// command for this router. Failures of the command are replied to the sender, only failing
+    /// to send the reply results in an error.
+    pub async fn handle(
+        &self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> Result<bool, GameError> {
+        match self.parse(galaxy, event) {
+            None => Ok(false),
+            Some(invocation) => {
+                let channel = invocation.channel;
+                let sender = Arc::clone(&invocation.sender);
+                let reply = match self.execute(invocation).await {
+                    Ok(reply) => reply,
+                    Err(ChatCommandError::UnknownCommand(_)) if !self.reply_unknown => None,
+                    Err(e) => Some(e.to_string()),
+                };
+
+                if let Some(reply) = reply {
+                    Self::reply(galaxy, &sender, channel, &reply).await?;
+                }
+
+                Ok(true)
+            }
+        }
+    }
+
+    /// Checks the permission and runs the command of the invocation, without replying.
+    pub async fn execute(
+        &self,
+        invocation: ChatInvocation,
+    ) -> Result<Option<String>, ChatCommandError> {
+        match self.command(&invocation.command) {
+            Some(command) => {
+                if command
+                    .permission()
+                    .allows(&invocation.galaxy, &invocation.sender)
+                {
+                    command.invoke(invocation).await
+                } else {
+                    Err(ChatCommandError::PermissionDenied)
+                }
+            }
+            None if self.help && invocation.command.eq_ignore_ascii_case("help") => {
+                self.help(&invocation)
+            }
+            None => Err(ChatCommandError::UnknownCommand(invocation.command)),
+        }
+    }
+
+    /// Sends the text to the given chat, split into valid chat messages.
+    pub async fn reply(
+        galaxy: &Galaxy,
+        sender: &Player,
+        channel: ChatChannel,
+        text: &str,
+    ) -> Result<(), GameError> {
+        for message in split_message(text) {
+            match channel {
+                ChatChannel::Galaxy => galaxy.chat(message).await?,
+                ChatChannel::Team => galaxy.player().team().chat(message).await?,
+                ChatChannel::Private => sender.chat(message).await?,
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn command(&self, name: &str) -> Option<&ChatCommand> {
+        self.commands.iter().find(|command| command.is_named(name))
+    }
+
+    fn help(&self, invocation: &ChatInvocation) -> Result<Option<String>, ChatCommandError> {
+        let allowed = |command: &&ChatCommand| {
+            command
+                .permission()
+                .allows(&invocation.galaxy, &invocation.sender)
+        };
+
+        let mut help = String::new();
+        match invocation.args.get(0) {
+            Some(name) => {
+                let command = self
+                    .command(name)
+                    .filter(allowed)
+                    .ok_or_else(|| ChatCommandError::UnknownCommand(name.to_string()))?;
+                help.push_str(&self.synopsis(command));
+                if !command.aliases().is_empty() {
+                    help.push_str("\nAliases: ");
+                    help.push_str(&command.aliases().join(", "));
+                }
+            }
+            None => {
+                for command in self.commands.iter().filter(allowed) {
+                    help.push_str(&self.synopsis(command));
+                    help.push('\n');
+                }
+                help.push_str(&format!("{}help [command]", self.prefix));
+            }
+        }
+
+        Ok(Some(help))
+    }
+
+    fn synopsis(&self, command: &ChatCommand) -> String {
+        let mut synopsis = format!("{}{}", self.prefix, command.name());
+        if !command.usage().is_empty() {
+            synopsis.push(' ');
+            synopsis.push_str(command.usage());
+        }
+        if !command.description().is_empty() {
+            synopsis.push_str(" - ");
+            synopsis.push_str(command.description());
+        }
+        synopsis
+    }
+}
@@ -0,0 +1,10 @@
+mod chat_command;
+pub use chat_command::*;
+
+mod chat_command_router;
+pub use chat_command_router::*;
+
+#[cfg(feature = "json")]
+mod bot_messenger;
+#[cfg(feature = "json")]
+pub use bot_messenger::*;
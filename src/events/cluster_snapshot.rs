@@ -1,7 +1,7 @@
 use crate::galaxy_hierarchy::{Cluster, ClusterId};
 
 /// Snapshot of a cluster state relevant for events.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ClusterSnapshot {
     /// Cluster id.
     pub id: ClusterId,
@@ -0,0 +1,147 @@
+use crate::galaxy_hierarchy::Controllable;
+use crate::FlattiverseEventKind;
+use std::sync::Arc;
+
+/// Coarse grouping of [`FlattiverseEventKind`]s, used to select whole families of events without
+/// listing every variant.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, strum::EnumIter)]
+pub enum EventCategory {
+    /// Connection state and connector-local diagnostics like ping measurements or lagging queues.
+    Connection,
+    /// Galaxy-wide settings and compile information.
+    Galaxy,
+    /// [`FlattiverseEventKind::GalaxyTick`].
+    Tick,
+    Team,
+    Cluster,
+    Player,
+    /// Roster changes of public controllable entries, including deaths and revivals.
+    ControllableInfo,
+    /// Appearance, updates and removal of visible units.
+    Unit,
+    /// Chat messages of players as well as system, message of the day and tournament messages.
+    Chat,
+    /// Gates being switched or restored.
+    Gate,
+    Tournament,
+    /// Runtime events of your own controllables which are not tied to one subsystem.
+    Controllable,
+    /// Per-tick runtime reports of the subsystems of your own controllables.
+    Subsystem,
+}
+
+impl EventCategory {
+    #[inline]
+    pub(crate) const fn mask(self) -> u32 {
+        1 << self as u8
+    }
+}
+
+impl FlattiverseEventKind {
+    /// The [`EventCategory`] this event belongs to.
+    pub fn category(&self) -> EventCategory {
+        match self {
+            Self::PlayerJoined { .. }
+            | Self::PlayerScoreUpdated { .. }
+            | Self::PlayerDisconnected { .. }
+            | Self::PlayerParted { .. }
+            | Self::PlayerUpdated { .. } => EventCategory::Player,
+            Self::ControllableInfoRegistered { .. }
+            | Self::ControllableInfoContinued { .. }
+            | Self::ControllableInfoDestroyed { .. }
+            | Self::ControllableInfoDestroyedByNeutralCollision { .. }
+            | Self::ControllableInfoDestroyedByPlayerUnit { .. }
+            | Self::ControllableInfoScoreUpdated { .. }
+            | Self::ControllableInfoClosed { .. } => EventCategory::ControllableInfo,
+            Self::UnitAppeared { .. }
+            | Self::UnitUpdated { .. }
+            | Self::UnitRemoved { .. }
+            | Self::UnitAlteredByAdmin { .. } => EventCategory::Unit,
+            Self::FlagScoredChat { .. }
+            | Self::DominationPointScoredChat { .. }
+            | Self::OwnFlagHitChat { .. }
+            | Self::GalaxyChat { .. }
+            | Self::TeamChat { .. }
+            | Self::PlayerChat { .. }
+            | Self::PlayerBinaryChat { .. }
+            | Self::MissionTargetHitChat { .. }
+            | Self::FlagReactivatedChat { .. }
+            | Self::SystemMessage { .. }
+            | Self::MotdMessage { .. }
+            | Self::TournamentMessage { .. } => EventCategory::Chat,
+            Self::GateSwitched { .. } | Self::GateRestored { .. } => EventCategory::Gate,
+            Self::ConnectionTerminated { .. }
+            | Self::PingMeasured(_)
+            | Self::EventsLagged { .. }
+            | Self::RespondedToPingMeasurement { .. } => EventCategory::Connection,
+            Self::GalaxyTick { .. } => EventCategory::Tick,
+            Self::GalaxySettingsUpdated { .. } | Self::CompiledWithMessage { .. } => {
+                EventCategory::Galaxy
+            }
+            Self::TeamCreated { .. }
+            | Self::TeamUpdated { .. }
+            | Self::TeamScoreUpdated { .. }
+            | Self::TeamRemoved { .. } => EventCategory::Team,
+            Self::ClusterCreated { .. }
+            | Self::ClusterUpdated { .. }
+            | Self::ClusterRemoved { .. } => EventCategory::Cluster,
+            Self::TournamentCreated { .. }
+            | Self::TournamentUpdated { .. }
+            | Self::TournamentRemoved { .. } => EventCategory::Tournament,
+            Self::PowerUpCollected { .. } | Self::EnvironmentDamage { .. } => {
+                EventCategory::Controllable
+            }
+            Self::ArmorSubsystem { .. }
+            | Self::BatterySubsystem { .. }
+            | Self::CargoSubsystem { .. }
+            | Self::EnergyCellSubsystem { .. }
+            | Self::DynamicScannerSubsystem { .. }
+            | Self::ClassicShipEngineSubsystem { .. }
+            | Self::HullSubsystem { .. }
+            | Self::NebulaCollectorSubsystem { .. }
+            | Self::ClassicRailgunSubsystem { .. }
+            | Self::ModernRailgunSubsystem { .. }
+            | Self::RepairSubsystem { .. }
+            | Self::ResourceMinerSubsystem { .. }
+            | Self::ShieldSubsystem { .. }
+            | Self::DynamicShotFabricatorSubsystem { .. }
+            | Self::DynamicInterceptorFabricatorSubsystem { .. }
+            | Self::DynamicShotLauncherSubsystem { .. }
+            | Self::DynamicInterceptorLauncherSubsystem { .. }
+            | Self::DynamicShotMagazineSubsystem { .. }
+            | Self::DynamicInterceptorMagazineSubsystem { .. }
+            | Self::ModernShipEngineSubsystem { .. } => EventCategory::Subsystem,
+        }
+    }
+
+    /// The own controllable a runtime event of the categories [`EventCategory::Controllable`] and
+    /// [`EventCategory::Subsystem`] refers to.
+    pub fn controllable(&self) -> Option<&Arc<Controllable>> {
+        match self {
+            Self::PowerUpCollected { controllable, .. }
+            | Self::EnvironmentDamage { controllable, .. }
+            | Self::ArmorSubsystem { controllable, .. }
+            | Self::BatterySubsystem { controllable, .. }
+            | Self::CargoSubsystem { controllable, .. }
+            | Self::EnergyCellSubsystem { controllable, .. }
+            | Self::DynamicScannerSubsystem { controllable, .. }
+            | Self::ClassicShipEngineSubsystem { controllable, .. }
+            | Self::HullSubsystem { controllable, .. }
+            | Self::NebulaCollectorSubsystem { controllable, .. }
+            | Self::ClassicRailgunSubsystem { controllable, .. }
+            | Self::ModernRailgunSubsystem { controllable, .. }
+            | Self::RepairSubsystem { controllable, .. }
+            | Self::ResourceMinerSubsystem { controllable, .. }
+            | Self::ShieldSubsystem { controllable, .. }
+            | Self::DynamicShotFabricatorSubsystem { controllable, .. }
+            | Self::DynamicInterceptorFabricatorSubsystem { controllable, .. }
+            | Self::DynamicShotLauncherSubsystem { controllable, .. }
+            | Self::DynamicInterceptorLauncherSubsystem { controllable, .. }
+            | Self::DynamicShotMagazineSubsystem { controllable, .. }
+            | Self::DynamicInterceptorMagazineSubsystem { controllable, .. }
+            | Self::ModernShipEngineSubsystem { controllable, .. } => Some(controllable),
+            _ => None,
+        }
+    }
+}
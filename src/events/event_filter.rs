@@ -0,0 +1,246 @@
+use crate::galaxy_hierarchy::{
+    ClusterId, ControllableId, ControllableInfo, Player, PlayerId, TeamId,
+};
+use crate::{EventCategory, FlattiverseEvent, FlattiverseEventKind};
+
+/// Selects the [`FlattiverseEvent`]s a subscription is interested in, see
+/// [`crate::galaxy_hierarchy::Galaxy::events_matching`].
+///
+/// Every dimension (category, cluster, player, team, controllable) is a set of accepted values.
+/// An event matches if it matches every constrained dimension, and it matches a dimension if it
+/// refers to at least one of its values. Events without any relation to a constrained dimension,
+/// like a [`FlattiverseEventKind::GalaxyTick`] for a cluster filter, never match. The default
+/// filter is unconstrained and matches everything.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventFilter {
+    categories: Option<u32>,
+    clusters: Vec<ClusterId>,
+    players: Vec<PlayerId>,
+    teams: Vec<TeamId>,
+    controllables: Vec<ControllableId>,
+}
+
+impl EventFilter {
+    /// Additionally accepts events of the given [`EventCategory`].
+    #[inline]
+    pub fn with_category(mut self, category: EventCategory) -> Self {
+        self.categories = Some(self.categories.unwrap_or_default() | category.mask());
+        self
+    }
+
+    /// Additionally accepts events of all the given [`EventCategory`]s.
+    #[inline]
+    pub fn with_categories(self, categories: impl IntoIterator<Item = EventCategory>) -> Self {
+        categories.into_iter().fold(self, Self::with_category)
+    }
+
+    /// Additionally accepts events happening in or concerning the given cluster.
+    #[inline]
+    pub fn with_cluster(mut self, cluster: ClusterId) -> Self {
+        push_unique(&mut self.clusters, cluster);
+        self
+    }
+
+    /// Additionally accepts events concerning the given player or one of their units.
+    #[inline]
+    pub fn with_player(mut self, player: PlayerId) -> Self {
+        push_unique(&mut self.players, player);
+        self
+    }
+
+    /// Additionally accepts events concerning the given team, its players or its units.
+    #[inline]
+    pub fn with_team(mut self, team: TeamId) -> Self {
+        push_unique(&mut self.teams, team);
+        self
+    }
+
+    /// Additionally accepts the runtime events of the given own controllable as well as the events
+    /// of its public [`crate::galaxy_hierarchy::ControllableInfo`], like its deaths and revivals.
+    #[inline]
+    pub fn with_controllable(mut self, controllable: ControllableId) -> Self {
+        push_unique(&mut self.controllables, controllable);
+        self
+    }
+
+    /// Whether this filter accepts the given event.
+    pub fn matches(&self, event: &FlattiverseEvent) -> bool {
+        let kind = event.kind();
+
+        if let Some(categories) = self.categories {
+            if categories & kind.category().mask() == 0 {
+                return false;
+            }
+        }
+
+        if !self.clusters.is_empty() && !any_cluster(kind, |id| self.clusters.contains(&id)) {
+            return false;
+        }
+
+        if !self.players.is_empty()
+            && !any_player(kind, |player| self.players.contains(&player.id()))
+        {
+            return false;
+        }
+
+        if !self.teams.is_empty() && !any_team(kind, |id| self.teams.contains(&id)) {
+            return false;
+        }
+
+        if !self.controllables.is_empty()
+            && !any_controllable(kind, |id| self.controllables.contains(&id))
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Whether this filter accepts every event.
+    #[inline]
+    pub fn is_unconstrained(&self) -> bool {
+        self.categories.is_none()
+            && self.clusters.is_empty()
+            && self.players.is_empty()
+            && self.teams.is_empty()
+            && self.controllables.is_empty()
+    }
+}
+
+#[inline]
+fn push_unique<T: PartialEq>(values: &mut Vec<T>, value: T) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+fn any_cluster(kind: &FlattiverseEventKind, mut f: impl FnMut(ClusterId) -> bool) -> bool {
+    use FlattiverseEventKind::*;
+    match kind {
+        UnitAppeared { unit } | UnitUpdated { unit, .. } | UnitRemoved { unit } => {
+            f(unit.cluster().id())
+        }
+        UnitAlteredByAdmin { cluster, .. } => f(*cluster),
+        GateSwitched { cluster, .. }
+        | GateRestored { cluster, .. }
+        | ClusterCreated { cluster }
+        | ClusterUpdated { cluster, .. }
+        | ClusterRemoved { cluster } => f(cluster.id()),
+        _ => match kind.controllable() {
+            Some(controllable) => f(controllable.cluster().id()),
+            None => false,
+        },
+    }
+}
+
+fn any_player(kind: &FlattiverseEventKind, mut f: impl FnMut(&Player) -> bool) -> bool {
+    use FlattiverseEventKind::*;
+    match kind {
+        PlayerJoined { player }
+        | PlayerScoreUpdated { player, .. }
+        | PlayerDisconnected { player }
+        | PlayerParted { player }
+        | PlayerUpdated { player }
+        | ControllableInfoRegistered { player, .. }
+        | ControllableInfoContinued { player, .. }
+        | ControllableInfoDestroyed { player, .. }
+        | ControllableInfoDestroyedByNeutralCollision { player, .. }
+        | ControllableInfoScoreUpdated { player, .. }
+        | ControllableInfoClosed { player, .. }
+        | FlagScoredChat { player, .. }
+        | OwnFlagHitChat { player, .. }
+        | GalaxyChat { player, .. }
+        | TeamChat { player, .. }
+        | PlayerChat { player, .. }
+        | PlayerBinaryChat { player, .. }
+        | MissionTargetHitChat { player, .. } => f(player),
+        ControllableInfoDestroyedByPlayerUnit {
+            player,
+            destroyer_player,
+            ..
+        } => f(player) || f(destroyer_player),
+        GateSwitched { invoker_player, .. } => invoker_player.as_deref().is_some_and(f),
+        UnitAppeared { unit } | UnitUpdated { unit, .. } | UnitRemoved { unit } => {
+            unit.as_player_unit().is_some_and(|unit| f(&unit.player()))
+        }
+        _ => false,
+    }
+}
+
+fn any_team(kind: &FlattiverseEventKind, mut f: impl FnMut(TeamId) -> bool) -> bool {
+    use FlattiverseEventKind::*;
+    let direct = match kind {
+        FlagScoredChat { flag_team, .. }
+        | OwnFlagHitChat { flag_team, .. }
+        | FlagReactivatedChat { flag_team, .. } => f(flag_team.id()),
+        DominationPointScoredChat { team, .. }
+        | TeamCreated { team }
+        | TeamUpdated { team, .. }
+        | TeamScoreUpdated { team, .. }
+        | TeamRemoved { team } => f(team.id()),
+        UnitAppeared { unit } | UnitUpdated { unit, .. } | UnitRemoved { unit } => {
+            unit.team().upgrade().is_some_and(|team| f(team.id()))
+        }
+        _ => false,
+    };
+
+    direct
+        || any_player(kind, |player| {
+            player
+                .team_weak()
+                .upgrade()
+                .is_some_and(|team| f(team.id()))
+        })
+}
+
+fn any_controllable(
+    kind: &FlattiverseEventKind,
+    mut f: impl FnMut(ControllableId) -> bool,
+) -> bool {
+    use FlattiverseEventKind::*;
+
+    // the public entry of an own controllable shares its id
+    let mut own = |player: &Player, info: &ControllableInfo| {
+        player.id() == player.galaxy().player().id() && f(ControllableId(info.id().0))
+    };
+
+    match kind {
+        ControllableInfoRegistered {
+            player,
+            controllable,
+        }
+        | ControllableInfoContinued {
+            player,
+            controllable,
+        }
+        | ControllableInfoDestroyed {
+            player,
+            controllable,
+            ..
+        }
+        | ControllableInfoDestroyedByNeutralCollision {
+            player,
+            controllable,
+            ..
+        }
+        | ControllableInfoScoreUpdated {
+            player,
+            controllable,
+            ..
+        }
+        | ControllableInfoClosed {
+            player,
+            controllable,
+        } => own(player, controllable),
+        ControllableInfoDestroyedByPlayerUnit {
+            player,
+            controllable,
+            destroyed_unit,
+            destroyer_player,
+            ..
+        } => own(player, controllable) || own(destroyer_player, destroyed_unit),
+        _ => kind
+            .controllable()
+            .is_some_and(|controllable| f(controllable.id())),
+    }
+}
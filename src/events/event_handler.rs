@@ -0,0 +1,277 @@
+use crate::galaxy_hierarchy::{ClusterId, Controllable, ControllableInfo, Galaxy};
+use crate::unit::{Unit, UnitChange};
+use crate::{EventCategory, FlattiverseEvent, FlattiverseEventKind, PlayerUnitDestroyedReason};
+use std::future::Future;
+use std::sync::Arc;
+
+/// Callbacks for the events of a galaxy, driven by [`Galaxy::run`].
+///
+/// Every method does nothing by default, so only the interesting ones have to be implemented. The
+/// methods may be implemented as `async fn`. They are awaited one after another, so while one of
+/// them runs, no further event is dispatched. For every event, [`EventHandler::on_event`] is called
+/// first, followed by the method of its kind.
+#[allow(unused_variables)]
+pub trait EventHandler: Send {
+    /// Called for every event before the more specific method.
+    fn on_event(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::GalaxyTick`], the server has processed the given tick.
+    fn on_tick(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        tick: u32,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Connector-local diagnostics like ping measurements or [`FlattiverseEventKind::EventsLagged`].
+    fn on_connection(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Galaxy settings and compile information.
+    fn on_galaxy(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_team(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_cluster(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_player(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Roster changes of public controllable entries, except for the ones dispatched to
+    /// [`EventHandler::on_controllable_continued`] and [`EventHandler::on_controllable_destroyed`].
+    fn on_controllable_info(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::ControllableInfoContinued`], the controllable is alive again.
+    fn on_controllable_continued(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        controllable: &Arc<ControllableInfo>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Any of the `ControllableInfoDestroyed*` events, the controllable has died.
+    fn on_controllable_destroyed(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        controllable: &Arc<ControllableInfo>,
+        reason: PlayerUnitDestroyedReason,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::UnitAppeared`]
+    fn on_unit_appeared(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        unit: &Arc<dyn Unit>,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::UnitUpdated`]
+    fn on_unit_updated(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        unit: &Arc<dyn Unit>,
+        change: &UnitChange,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::UnitRemoved`]
+    fn on_unit_removed(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        unit: &Arc<dyn Unit>,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// [`FlattiverseEventKind::UnitAlteredByAdmin`]
+    fn on_unit_altered_by_admin(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        cluster: ClusterId,
+        name: &str,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Chat messages of players as well as system, message of the day and tournament messages.
+    fn on_chat(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_gate(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_tournament(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Runtime events of an own controllable which are not tied to one subsystem, like
+    /// [`FlattiverseEventKind::PowerUpCollected`].
+    fn on_controllable(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        controllable: &Arc<Controllable>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Per-tick runtime report of a subsystem of an own controllable.
+    fn on_subsystem_update(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        controllable: &Arc<Controllable>,
+        event: &FlattiverseEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// The connection has terminated, no further event will be dispatched.
+    fn on_terminated(
+        &mut self,
+        galaxy: &Arc<Galaxy>,
+        reason: Option<&str>,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// The shutdown signal passed to [`Galaxy::run_until`] has completed, no further event will
+    /// be dispatched. The connection is still open.
+    fn on_shutdown(&mut self, galaxy: &Arc<Galaxy>) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+/// Calls the methods of the handler for the given event.
+pub(crate) async fn dispatch(
+    handler: &mut impl EventHandler,
+    galaxy: &Arc<Galaxy>,
+    event: &FlattiverseEvent,
+) {
+    use FlattiverseEventKind::*;
+
+    handler.on_event(galaxy, event).await;
+
+    match event.kind() {
+        GalaxyTick { tick, .. } => handler.on_tick(galaxy, *tick, event).await,
+        UnitAppeared { unit } => handler.on_unit_appeared(galaxy, unit).await,
+        UnitUpdated { unit, change } => handler.on_unit_updated(galaxy, unit, change).await,
+        UnitRemoved { unit } => handler.on_unit_removed(galaxy, unit).await,
+        UnitAlteredByAdmin { cluster, name } => {
+            handler
+                .on_unit_altered_by_admin(galaxy, *cluster, name)
+                .await
+        }
+        ControllableInfoContinued { controllable, .. } => {
+            handler
+                .on_controllable_continued(galaxy, controllable, event)
+                .await
+        }
+        ControllableInfoDestroyed {
+            controllable,
+            reason,
+            ..
+        }
+        | ControllableInfoDestroyedByNeutralCollision {
+            controllable,
+            reason,
+            ..
+        }
+        | ControllableInfoDestroyedByPlayerUnit {
+            controllable,
+            reason,
+            ..
+        } => {
+            handler
+                .on_controllable_destroyed(galaxy, controllable, *reason, event)
+                .await
+        }
+        ConnectionTerminated { message } => handler.on_terminated(galaxy, message.as_deref()).await,
+        kind => match kind.category() {
+            EventCategory::Connection => handler.on_connection(galaxy, event).await,
+            EventCategory::Galaxy => handler.on_galaxy(galaxy, event).await,
+            EventCategory::Team => handler.on_team(galaxy, event).await,
+            EventCategory::Cluster => handler.on_cluster(galaxy, event).await,
+            EventCategory::Player => handler.on_player(galaxy, event).await,
+            EventCategory::ControllableInfo => handler.on_controllable_info(galaxy, event).await,
+            EventCategory::Chat => handler.on_chat(galaxy, event).await,
+            EventCategory::Gate => handler.on_gate(galaxy, event).await,
+            EventCategory::Tournament => handler.on_tournament(galaxy, event).await,
+            EventCategory::Controllable | EventCategory::Subsystem => {
+                if let Some(controllable) = kind.controllable() {
+                    if kind.category() == EventCategory::Subsystem {
+                        handler
+                            .on_subsystem_update(galaxy, controllable, event)
+                            .await
+                    } else {
+                        handler.on_controllable(galaxy, controllable, event).await
+                    }
+                }
+            }
+            // dispatched above
+            EventCategory::Tick | EventCategory::Unit => {}
+        },
+    }
+}
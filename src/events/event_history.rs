@@ -0,0 +1,122 @@
+use crate::utils::Atomic;
+use crate::{FlattiverseEvent, FlattiverseEventKind};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// How much an event history keeps, see
+/// [`crate::galaxy_hierarchy::Galaxy::set_event_history_limit`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EventHistoryLimit {
+    /// The given amount of most recent events.
+    Events(usize),
+    /// The events of the given amount of most recent ticks, each including its
+    /// [`FlattiverseEventKind::GalaxyTick`], plus the events of the tick in progress.
+    Ticks(usize),
+}
+
+#[derive(Debug, Default)]
+struct History {
+    limit: Option<EventHistoryLimit>,
+    events: VecDeque<FlattiverseEvent>,
+    ticks: usize,
+}
+
+impl History {
+    fn trim(&mut self) {
+        match self.limit {
+            None => {
+                self.events.clear();
+                self.ticks = 0;
+            }
+            Some(EventHistoryLimit::Events(limit)) => {
+                while self.events.len() > limit {
+                    self.pop_front();
+                }
+            }
+            Some(EventHistoryLimit::Ticks(limit)) => {
+                while self.ticks > limit {
+                    // removes the oldest tick up to and including its GalaxyTick
+                    while let Some(event) = self.pop_front() {
+                        if is_tick(&event) {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<FlattiverseEvent> {
+        let event = self.events.pop_front()?;
+        if is_tick(&event) {
+            self.ticks -= 1;
+        }
+        Some(event)
+    }
+}
+
+#[inline]
+fn is_tick(event: &FlattiverseEvent) -> bool {
+    matches!(event.kind(), FlattiverseEventKind::GalaxyTick { .. })
+}
+
+/// Optional in-memory ring buffer of the most recent events of a connection.
+#[derive(Debug, Default)]
+pub(crate) struct EventHistory {
+    enabled: Atomic<bool>,
+    history: Mutex<History>,
+}
+
+impl EventHistory {
+    pub(crate) fn set_limit(&self, limit: Option<EventHistoryLimit>) {
+        let mut history = self.history.lock().unwrap();
+        history.limit = limit;
+        history.trim();
+        self.enabled.store(limit.is_some());
+    }
+
+    #[inline]
+    pub(crate) fn limit(&self) -> Option<EventHistoryLimit> {
+        self.history.lock().unwrap().limit
+    }
+
+    /// Records the event and calls `publish` while the history is locked, so
+    /// [`EventHistory::replay`] sees every event either in the history or published.
+    #[inline]
+    pub(crate) fn record(&self, event: &FlattiverseEvent, publish: impl FnOnce()) {
+        if self.enabled.load() {
+            let mut history = self.history.lock().unwrap();
+            if history.limit.is_some() {
+                if is_tick(event) {
+                    history.ticks += 1;
+                }
+                history.events.push_back(event.clone());
+                history.trim();
+            }
+            publish();
+        } else {
+            publish();
+        }
+    }
+
+    /// Calls `f` with the recorded events, oldest first, while no further events are published.
+    pub(crate) fn replay<R>(
+        &self,
+        f: impl FnOnce(&mut dyn Iterator<Item = &FlattiverseEvent>) -> R,
+    ) -> R {
+        f(&mut self.history.lock().unwrap().events.iter())
+    }
+
+    /// The recorded events, oldest first.
+    #[inline]
+    pub(crate) fn events(&self) -> Vec<FlattiverseEvent> {
+        self.history
+            .lock()
+            .unwrap()
+            .events
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
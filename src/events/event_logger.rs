@@ -0,0 +1,64 @@
+use crate::{EventRecord, FlattiverseEvent, FlattiverseEventKind};
+use futures_core::Stream;
+use std::io::Write;
+use std::pin::Pin;
+
+/// Writes [`FlattiverseEvent`]s as [`EventRecord`]s to a writer, one JSON object per line
+/// ([JSON Lines](https://jsonlines.org/)).
+///
+/// The writer is flushed after every [`FlattiverseEventKind::GalaxyTick`] and when the connection
+/// terminated, so wrapping it into a [`std::io::BufWriter`] is recommended. Only available with the
+/// `json` feature.
+#[derive(Debug)]
+pub struct EventLogger<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> EventLogger<W> {
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes one event as a single line.
+    pub fn log(&mut self, event: &FlattiverseEvent) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.writer, &EventRecord::from(event))?;
+        self.writer.write_all(b"\n")?;
+
+        if matches!(
+            event.kind(),
+            FlattiverseEventKind::GalaxyTick { .. }
+                | FlattiverseEventKind::ConnectionTerminated { .. }
+        ) {
+            self.writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Logs every event of the stream until it ends, usually with the final
+    /// [`FlattiverseEventKind::ConnectionTerminated`] of a [`crate::EventStream`], and returns the
+    /// flushed writer.
+    pub async fn run(
+        mut self,
+        mut events: impl Stream<Item = FlattiverseEvent> + Unpin,
+    ) -> std::io::Result<W> {
+        while let Some(event) = std::future::poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await
+        {
+            self.log(&event)?;
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -0,0 +1,426 @@
+use crate::utils::Atomic;
+use crate::{FlattiverseEvent, FlattiverseEventKind, GameError, GameErrorKind};
+use arc_swap::ArcSwap;
+use async_channel::{Receiver, Sender};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// Decides what happens to a new event if a bounded event queue is full.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discards the oldest queued event to make room for the new one.
+    #[default]
+    DropOldest,
+    /// Discards the new event and keeps the queued ones.
+    DropNewest,
+    /// Keeps every event, but the connection stops applying packets until the queue has room
+    /// again. It keeps reading and buffers the packets meanwhile, so replies to commands still
+    /// arrive. As a reply may refer to anything the server sent before it, the buffered packets
+    /// are applied first, which lets the queue exceed its capacity.
+    ///
+    /// On wasm the connection can't be paused and the queue grows like an unbounded one.
+    Block,
+    /// Merges a [`FlattiverseEventKind::UnitUpdated`] into the queued update of the same unit, if
+    /// there is one. The consumer receives a single update whose [`crate::unit::UnitChange`]
+    /// holds the values from before the queued update and after the merged ones, so these are not
+    /// reported as dropped. Other events are handled like [`OverflowPolicy::DropOldest`].
+    CoalesceUnitUpdates,
+    /// Closes the queue. The consumer still receives the queued events, followed by
+    /// [`GameErrorKind::EventQueueOverflowed`]. For the queue of
+    /// [`crate::galaxy_hierarchy::Galaxy::next_event`] this terminates the connection.
+    Disconnect,
+}
+
+/// Buffer configuration of an event queue, see [`crate::EventSubscription`] and
+/// [`crate::galaxy_hierarchy::Galaxy::set_event_queue_options`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EventQueueOptions {
+    capacity: Option<usize>,
+    overflow_policy: OverflowPolicy,
+}
+
+impl Default for EventQueueOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::bounded(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl EventQueueOptions {
+    /// Enough for the events of several busy ticks.
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// A queue that holds at most `capacity` events. What happens to further events is decided by
+    /// the [`OverflowPolicy`].
+    #[inline]
+    pub fn bounded(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity.max(1)),
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+
+    /// A queue without limit. A consumer that falls behind makes it grow without bounds.
+    #[inline]
+    pub fn unbounded() -> Self {
+        Self {
+            capacity: None,
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// The maximum amount of queued events, `None` if unbounded.
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    #[inline]
+    fn blocks(&self) -> bool {
+        self.capacity.is_some() && self.overflow_policy == OverflowPolicy::Block
+    }
+}
+
+/// Bookkeeping shared by the sending and the receiving side of an event queue.
+#[derive(Debug, Default)]
+pub(crate) struct QueueState {
+    dropped: AtomicU64,
+    /// The tick of the most recently pushed event, reported with
+    /// [`FlattiverseEventKind::EventsLagged`].
+    latest_tick: Atomic<Option<u32>>,
+    overflowed: Atomic<bool>,
+    blocking: Atomic<bool>,
+    space: Notify,
+    /// Queued [`FlattiverseEventKind::UnitUpdated`] per unit, for
+    /// [`OverflowPolicy::CoalesceUnitUpdates`].
+    pending_updates: Mutex<HashMap<usize, PendingUpdates>>,
+}
+
+/// The updates of a unit in a queue with [`OverflowPolicy::CoalesceUnitUpdates`].
+#[derive(Debug, Default)]
+struct PendingUpdates {
+    queued: usize,
+    /// The updates which were not queued, merged into one. They are merged into the last queued
+    /// update once the consumer receives it.
+    merged: Option<FlattiverseEvent>,
+}
+
+impl QueueState {
+    /// Returns a [`FlattiverseEventKind::EventsLagged`] event if events were dropped since the last
+    /// call.
+    pub(crate) fn take_lagged_event(&self) -> Option<FlattiverseEvent> {
+        match self.dropped.swap(0, Ordering::Relaxed) {
+            0 => None,
+            dropped => {
+                let mut event =
+                    FlattiverseEvent::from(FlattiverseEventKind::EventsLagged { dropped });
+                event.stamp_tick(self.latest_tick.load());
+                Some(event)
+            }
+        }
+    }
+
+    pub(crate) fn closed_error(&self, reason: &str) -> GameError {
+        if self.overflowed.load() {
+            GameErrorKind::EventQueueOverflowed.into()
+        } else {
+            GameErrorKind::ConnectionTerminated {
+                reason: Some(Arc::from(reason)),
+            }
+            .into()
+        }
+    }
+
+    /// The [`FlattiverseEventKind::ConnectionTerminated`] reported if a queue has been closed
+    /// without delivering one, which happens on overflow or if the connection vanished.
+    pub(crate) fn termination_event(&self) -> FlattiverseEvent {
+        FlattiverseEventKind::ConnectionTerminated {
+            message: self
+                .overflowed
+                .load()
+                .then(|| GameErrorKind::EventQueueOverflowed.to_string()),
+        }
+        .into()
+    }
+
+    /// Has to be called by the consumer for every event taken from the queue. Returns the event to
+    /// hand out, which includes the updates merged into it.
+    pub(crate) fn received(&self, event: FlattiverseEvent) -> FlattiverseEvent {
+        let event = match self.forget_update(&event) {
+            Some(merged) => event.merged_unit_update(&merged).unwrap_or(event),
+            None => event,
+        };
+        if self.blocking.load() {
+            self.space.notify_waiters();
+        }
+        event
+    }
+
+    #[inline]
+    fn update_key(event: &FlattiverseEvent) -> Option<usize> {
+        match event.kind() {
+            FlattiverseEventKind::UnitUpdated { unit, .. } => {
+                Some(Arc::as_ptr(unit) as *const () as usize)
+            }
+            _ => None,
+        }
+    }
+
+    fn remember_update(&self, event: &FlattiverseEvent) {
+        if let Some(key) = Self::update_key(event) {
+            self.pending_updates
+                .lock()
+                .unwrap()
+                .entry(key)
+                .or_default()
+                .queued += 1;
+        }
+    }
+
+    /// Returns the merged updates once the last queued update of the unit leaves the queue.
+    fn forget_update(&self, event: &FlattiverseEvent) -> Option<FlattiverseEvent> {
+        let key = Self::update_key(event)?;
+        let mut pending = self.pending_updates.lock().unwrap();
+        let updates = pending.get_mut(&key)?;
+        updates.queued -= 1;
+        if updates.queued == 0 {
+            pending.remove(&key)?.merged
+        } else {
+            None
+        }
+    }
+
+    /// Merges the update into a queued one of the same unit. Returns `false` if there is none.
+    fn merge_update(&self, event: &FlattiverseEvent) -> bool {
+        let Some(key) = Self::update_key(event) else {
+            return false;
+        };
+        let mut pending = self.pending_updates.lock().unwrap();
+        let Some(updates) = pending.get_mut(&key) else {
+            return false;
+        };
+        updates.merged = Some(match updates.merged.take() {
+            Some(merged) => merged
+                .merged_unit_update(event)
+                .unwrap_or_else(|| event.clone()),
+            None => event.clone(),
+        });
+        true
+    }
+}
+
+/// Receiving side's share of a [`QueueState`]. Declared after the receiver, it wakes a connection
+/// blocked by [`OverflowPolicy::Block`] once the receiver is gone.
+#[derive(Debug)]
+pub(crate) struct ConsumerState(Arc<QueueState>);
+
+impl ConsumerState {
+    #[inline]
+    pub(crate) fn new(state: Arc<QueueState>) -> Self {
+        Self(state)
+    }
+}
+
+impl Deref for ConsumerState {
+    type Target = QueueState;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for ConsumerState {
+    #[inline]
+    fn drop(&mut self) {
+        if self.0.blocking.load() {
+            self.0.space.notify_waiters();
+        }
+    }
+}
+
+/// Sending side of an event queue which enforces the [`EventQueueOptions`].
+///
+/// The channel itself is unbounded, so the options can be changed at any time. The queue keeps a
+/// receiver of its own to discard the oldest events, the consumer is considered gone once it is
+/// the last one.
+pub(crate) struct EventQueue {
+    sender: Sender<FlattiverseEvent>,
+    receiver: Receiver<FlattiverseEvent>,
+    options: ArcSwap<EventQueueOptions>,
+    state: Arc<QueueState>,
+}
+
+impl Debug for EventQueue {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventQueue")
+            .field("len", &self.sender.len())
+            .field("options", &**self.options.load())
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventQueue {
+    /// Creates a queue and the receiver for its consumer.
+    pub(crate) fn new(options: EventQueueOptions) -> (Self, Receiver<FlattiverseEvent>) {
+        let (sender, receiver) = async_channel::unbounded();
+        let state = Arc::new(QueueState::default());
+        state.blocking.store(options.blocks());
+
+        let queue = Self {
+            sender,
+            receiver: receiver.clone(),
+            options: ArcSwap::new(Arc::new(options)),
+            state,
+        };
+
+        (queue, receiver)
+    }
+
+    #[inline]
+    pub(crate) fn state(&self) -> &Arc<QueueState> {
+        &self.state
+    }
+
+    #[inline]
+    pub(crate) fn options(&self) -> EventQueueOptions {
+        **self.options.load()
+    }
+
+    pub(crate) fn set_options(&self, options: EventQueueOptions) {
+        self.options.store(Arc::new(options));
+        self.state.blocking.store(options.blocks());
+        // a blocked connection might be allowed to continue now
+        self.state.space.notify_waiters();
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    /// Whether the queue has been closed or its consumer is gone.
+    #[inline]
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed() || self.sender.receiver_count() <= 1
+    }
+
+    /// Closes the queue. The consumer still receives the already queued events.
+    #[inline]
+    pub(crate) fn close(&self) {
+        self.sender.close();
+        self.state.space.notify_waiters();
+    }
+
+    /// Enqueues the event according to the [`OverflowPolicy`] and returns `false` if the queue is
+    /// closed afterward.
+    pub(crate) fn push(&self, event: FlattiverseEvent) -> bool {
+        if self.is_closed() {
+            return false;
+        }
+
+        self.state.latest_tick.store(event.tick());
+        let options = self.options.load();
+        let full = options
+            .capacity
+            .is_some_and(|capacity| self.sender.len() >= capacity);
+
+        if full {
+            match options.overflow_policy {
+                OverflowPolicy::DropOldest => self.drop_oldest(),
+                OverflowPolicy::DropNewest => {
+                    self.state.dropped.fetch_add(1, Ordering::Relaxed);
+                    return true;
+                }
+                OverflowPolicy::Block => {}
+                OverflowPolicy::CoalesceUnitUpdates => {
+                    if self.state.merge_update(&event) {
+                        return true;
+                    }
+                    self.drop_oldest();
+                }
+                OverflowPolicy::Disconnect => {
+                    warn!("Event queue overflowed, disconnecting it");
+                    self.state.overflowed.store(true);
+                    self.sender.close();
+                    return false;
+                }
+            }
+        }
+
+        if options.overflow_policy == OverflowPolicy::CoalesceUnitUpdates {
+            self.state.remember_update(&event);
+        }
+
+        self.sender.try_send(event).is_ok()
+    }
+
+    /// Enqueues the event regardless of the capacity, used for the final
+    /// [`FlattiverseEventKind::ConnectionTerminated`].
+    #[inline]
+    pub(crate) fn push_unbounded(&self, event: FlattiverseEvent) {
+        let _ = self.sender.try_send(event);
+    }
+
+    fn drop_oldest(&self) {
+        if let Ok(oldest) = self.receiver.try_recv() {
+            // the updates merged into it are gone as well
+            let merged = self.state.forget_update(&oldest).is_some();
+            self.state
+                .dropped
+                .fetch_add(1 + u64::from(merged), Ordering::Relaxed);
+        }
+    }
+
+    /// Whether the queue has room, which is always the case unless it uses
+    /// [`OverflowPolicy::Block`].
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) fn has_space(&self) -> bool {
+        let options = self.options.load();
+        match options.capacity {
+            Some(capacity) if options.blocks() && !self.is_closed() => self.sender.len() < capacity,
+            _ => true,
+        }
+    }
+
+    /// Waits until a queue with [`OverflowPolicy::Block`] has room again.
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) async fn wait_for_space(&self) {
+        loop {
+            // registered before checking, so no notification in between can be missed
+            let space = self.state.space.notified();
+            if self.has_space() {
+                return;
+            }
+            space.await;
+        }
+    }
+}
@@ -0,0 +1,1398 @@
+use crate::galaxy_hierarchy::{
+    Cluster, ClusterId, Controllable, ControllableId, ControllableInfo, ControllableInfoId, Player,
+    PlayerId, RailgunDirection, Score, Team, TeamId, Tournament, TournamentMode, TournamentStage,
+};
+use crate::unit::{Unit, UnitChange, UnitKind};
+use crate::{
+    ClusterSnapshot, FlattiverseEvent, FlattiverseEventKind, GalaxySettingsSnapshot,
+    GateStateChange, PlayerUnitDestroyedReason, SubsystemSlot, SubsystemStatus, TeamSnapshot,
+    Vector,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+/// Owned, serializable copy of a [`FlattiverseEvent`].
+///
+/// All handles of the event are resolved to plain values when the record is created: players,
+/// teams, clusters and controllables to their ids and names, units and scores to a snapshot of
+/// their current state. A record therefore stays meaningful after the referenced objects changed
+/// or vanished and can be written to disk, for example by the `EventLogger` of the `json` feature.
+///
+/// Serialized, the event kind is stored in the `event` field next to the fields of the kind.
+#[derive(Debug, Clone, Serialize)]
+pub struct EventRecord {
+    /// Milliseconds since the unix epoch when the event was created inside the connector, see
+    /// [`FlattiverseEvent::timestamp`].
+    pub timestamp_ms: u64,
+    /// See [`FlattiverseEvent::tick`].
+    pub tick: Option<u32>,
+    #[serde(flatten)]
+    pub kind: EventRecordKind,
+}
+
+impl From<&FlattiverseEvent> for EventRecord {
+    fn from(event: &FlattiverseEvent) -> Self {
+        Self {
+            timestamp_ms: event
+                .timestamp()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            tick: event.tick(),
+            kind: EventRecordKind::from(event.kind()),
+        }
+    }
+}
+
+/// Identifies a [`Player`] in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerRef {
+    pub id: PlayerId,
+    pub name: String,
+    /// `None` if the team is already gone.
+    pub team: Option<TeamId>,
+}
+
+impl From<&Player> for PlayerRef {
+    fn from(player: &Player) -> Self {
+        Self {
+            id: player.id(),
+            name: player.name().to_string(),
+            team: player.team_weak().upgrade().map(|team| team.id()),
+        }
+    }
+}
+
+/// Identifies a [`Team`] in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamRef {
+    pub id: TeamId,
+    pub name: String,
+}
+
+impl From<&Team> for TeamRef {
+    fn from(team: &Team) -> Self {
+        Self {
+            id: team.id(),
+            name: team.name().to_string(),
+        }
+    }
+}
+
+/// Identifies a [`Cluster`] in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterRef {
+    pub id: ClusterId,
+    pub name: String,
+}
+
+impl From<&Cluster> for ClusterRef {
+    fn from(cluster: &Cluster) -> Self {
+        Self {
+            id: cluster.id(),
+            name: cluster.name().to_string(),
+        }
+    }
+}
+
+/// Identifies a [`ControllableInfo`] in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ControllableInfoRef {
+    pub id: ControllableInfoId,
+    pub name: String,
+    /// The owner of the controllable.
+    pub player: PlayerId,
+}
+
+impl From<&ControllableInfo> for ControllableInfoRef {
+    fn from(info: &ControllableInfo) -> Self {
+        Self {
+            id: info.id(),
+            name: info.name().to_string(),
+            player: info.player().id(),
+        }
+    }
+}
+
+/// Identifies one of your own [`Controllable`]s in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ControllableRef {
+    pub id: ControllableId,
+    pub name: String,
+}
+
+impl From<&Controllable> for ControllableRef {
+    fn from(controllable: &Controllable) -> Self {
+        Self {
+            id: controllable.id(),
+            name: controllable.name().to_string(),
+        }
+    }
+}
+
+/// Value copy of a [`Score`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreRecord {
+    pub player_kills: u32,
+    pub player_deaths: u32,
+    pub friendly_kills: u32,
+    pub friendly_deaths: u32,
+    pub npc_kills: u32,
+    pub npc_deaths: u32,
+    pub neutral_deaths: u32,
+    pub mission: i32,
+}
+
+impl From<&Score> for ScoreRecord {
+    fn from(score: &Score) -> Self {
+        Self {
+            player_kills: score.player_kills(),
+            player_deaths: score.player_deaths(),
+            friendly_kills: score.friendly_kills(),
+            friendly_deaths: score.friendly_deaths(),
+            npc_kills: score.npc_kills(),
+            npc_deaths: score.npc_deaths(),
+            neutral_deaths: score.neutral_deaths(),
+            mission: score.mission(),
+        }
+    }
+}
+
+/// Snapshot of the common state of a [`Unit`] in an [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+pub struct UnitRecord {
+    pub name: String,
+    pub kind: UnitKind,
+    pub cluster: ClusterId,
+    pub position: Vector,
+    pub movement: Vector,
+    pub angle: f32,
+    pub radius: f32,
+    /// `None` for units without a team.
+    pub team: Option<TeamId>,
+    /// The owner, only set for player units.
+    pub player: Option<PlayerId>,
+    /// The controllable entry, only set for player units.
+    pub controllable: Option<ControllableInfoId>,
+}
+
+impl From<&dyn Unit> for UnitRecord {
+    fn from(unit: &dyn Unit) -> Self {
+        let player_unit = unit.as_player_unit();
+        Self {
+            name: unit.name().to_string(),
+            kind: unit.kind(),
+            cluster: unit.cluster().id(),
+            position: unit.position(),
+            movement: unit.movement(),
+            angle: unit.angle(),
+            radius: unit.radius(),
+            team: unit.team().upgrade().map(|team| team.id()),
+            player: player_unit.map(|unit| unit.player().id()),
+            controllable: player_unit.map(|unit| unit.controllable_info().id()),
+        }
+    }
+}
+
+/// Value copy of a [`Tournament`].
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentRecord {
+    pub stage: TournamentStage,
+    pub mode: TournamentMode,
+    pub duration_ticks: u32,
+    pub teams: Vec<TournamentTeamRecord>,
+    pub match_history: Vec<TournamentMatchRecord>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentTeamRecord {
+    pub team: TeamRef,
+    /// Account names of the participants.
+    pub participants: Vec<String>,
+    pub wins: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentMatchRecord {
+    pub match_number: i32,
+    pub winning_team: TeamId,
+}
+
+impl From<&Tournament> for TournamentRecord {
+    fn from(tournament: &Tournament) -> Self {
+        Self {
+            stage: *tournament.stage(),
+            mode: *tournament.mode(),
+            duration_ticks: tournament.duration_ticks(),
+            teams: tournament
+                .teams()
+                .iter()
+                .map(|team| TournamentTeamRecord {
+                    team: TeamRef::from(&**team.team()),
+                    participants: team
+                        .participants()
+                        .iter()
+                        .map(|account| account.name().to_string())
+                        .collect(),
+                    wins: team.wins(),
+                })
+                .collect(),
+            match_history: tournament
+                .match_history()
+                .iter()
+                .map(|result| TournamentMatchRecord {
+                    match_number: result.match_number(),
+                    winning_team: result.winning_team().id(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Serializable counterpart of [`FlattiverseEventKind`], see [`EventRecord`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event")]
+pub enum EventRecordKind {
+    /// See [`FlattiverseEventKind::PlayerJoined`].
+    PlayerJoined { player: PlayerRef },
+    /// See [`FlattiverseEventKind::PlayerScoreUpdated`].
+    PlayerScoreUpdated {
+        player: PlayerRef,
+        before: ScoreRecord,
+    },
+    /// See [`FlattiverseEventKind::PlayerDisconnected`].
+    PlayerDisconnected { player: PlayerRef },
+    /// See [`FlattiverseEventKind::PlayerParted`].
+    PlayerParted { player: PlayerRef },
+    /// See [`FlattiverseEventKind::ControllableInfoRegistered`].
+    ControllableInfoRegistered {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoContinued`].
+    ControllableInfoContinued {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoDestroyed`].
+    ControllableInfoDestroyed {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+        reason: PlayerUnitDestroyedReason,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoDestroyedByNeutralCollision`].
+    ControllableInfoDestroyedByNeutralCollision {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+        reason: PlayerUnitDestroyedReason,
+        colliders_kind: UnitKind,
+        colliders_name: String,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoDestroyedByPlayerUnit`].
+    ControllableInfoDestroyedByPlayerUnit {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+        reason: PlayerUnitDestroyedReason,
+        destroyed_unit: ControllableInfoRef,
+        destroyer_player: PlayerRef,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoScoreUpdated`].
+    ControllableInfoScoreUpdated {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+        before: ScoreRecord,
+    },
+    /// See [`FlattiverseEventKind::ControllableInfoClosed`].
+    ControllableInfoClosed {
+        player: PlayerRef,
+        controllable: ControllableInfoRef,
+    },
+    /// See [`FlattiverseEventKind::UnitAppeared`].
+    UnitAppeared { unit: UnitRecord },
+    /// See [`FlattiverseEventKind::UnitUpdated`].
+    UnitUpdated {
+        unit: UnitRecord,
+        change: UnitChange,
+    },
+    /// See [`FlattiverseEventKind::UnitRemoved`].
+    UnitRemoved { unit: UnitRecord },
+    /// See [`FlattiverseEventKind::UnitAlteredByAdmin`].
+    UnitAlteredByAdmin { cluster: ClusterId, name: String },
+    /// See [`FlattiverseEventKind::FlagScoredChat`].
+    FlagScoredChat {
+        player: PlayerRef,
+        controllable_info: ControllableInfoRef,
+        flag_team: TeamRef,
+        flag_name: String,
+    },
+    /// See [`FlattiverseEventKind::DominationPointScoredChat`].
+    DominationPointScoredChat {
+        team: TeamRef,
+        domination_point_name: String,
+    },
+    /// See [`FlattiverseEventKind::OwnFlagHitChat`].
+    OwnFlagHitChat {
+        player: PlayerRef,
+        controllable_info: ControllableInfoRef,
+        flag_team: TeamRef,
+        flag_name: String,
+    },
+    /// See [`FlattiverseEventKind::GalaxyChat`].
+    GalaxyChat {
+        player: PlayerRef,
+        destination: String,
+        message: String,
+    },
+    /// See [`FlattiverseEventKind::TeamChat`].
+    TeamChat {
+        player: PlayerRef,
+        destination: PlayerRef,
+        message: String,
+    },
+    /// See [`FlattiverseEventKind::PlayerChat`].
+    PlayerChat {
+        player: PlayerRef,
+        destination: PlayerRef,
+        message: String,
+    },
+    /// See [`FlattiverseEventKind::PlayerBinaryChat`].
+    PlayerBinaryChat {
+        player: PlayerRef,
+        destination: PlayerRef,
+        message: Vec<u8>,
+    },
+    /// See [`FlattiverseEventKind::MissionTargetHitChat`].
+    MissionTargetHitChat {
+        player: PlayerRef,
+        controllable_info: ControllableInfoRef,
+        mission_target_sequence: u16,
+    },
+    /// See [`FlattiverseEventKind::FlagReactivatedChat`].
+    FlagReactivatedChat {
+        flag_team: TeamRef,
+        flag_name: String,
+    },
+    /// See [`FlattiverseEventKind::SystemMessage`].
+    SystemMessage { message: String },
+    /// See [`FlattiverseEventKind::MotdMessage`].
+    MotdMessage { message: String },
+    /// See [`FlattiverseEventKind::GateSwitched`].
+    GateSwitched {
+        cluster: ClusterRef,
+        invoker_player: Option<PlayerRef>,
+        invoker_controllable_info: Option<ControllableInfoRef>,
+        switch_name: String,
+        gates: Vec<GateStateChange>,
+    },
+    /// See [`FlattiverseEventKind::GateRestored`].
+    GateRestored {
+        cluster: ClusterRef,
+        gate_name: String,
+        closed: bool,
+    },
+    /// See [`FlattiverseEventKind::ConnectionTerminated`].
+    ConnectionTerminated { message: Option<String> },
+    /// See [`FlattiverseEventKind::GalaxyTick`].
+    GalaxyTick {
+        tick: u32,
+        scan_ms: f32,
+        steady_ms: f32,
+        gravity_ms: f32,
+        engines_ms: f32,
+        limit_ms: f32,
+        movement_ms: f32,
+        collisions_ms: f32,
+        actions_ms: f32,
+        visibility_ms: f32,
+        total_ms: f32,
+        remaining_static_segments: i32,
+    },
+    /// See [`FlattiverseEventKind::GalaxySettingsUpdated`].
+    GalaxySettingsUpdated {
+        galaxy: String,
+        before: Option<GalaxySettingsSnapshot>,
+    },
+    /// See [`FlattiverseEventKind::TeamCreated`].
+    TeamCreated { team: TeamRef },
+    /// See [`FlattiverseEventKind::TeamUpdated`].
+    TeamUpdated { team: TeamRef, before: TeamSnapshot },
+    /// See [`FlattiverseEventKind::TeamScoreUpdated`].
+    TeamScoreUpdated { team: TeamRef, before: ScoreRecord },
+    /// See [`FlattiverseEventKind::TeamRemoved`].
+    TeamRemoved { team: TeamRef },
+    /// See [`FlattiverseEventKind::ClusterCreated`].
+    ClusterCreated { cluster: ClusterRef },
+    /// See [`FlattiverseEventKind::ClusterUpdated`].
+    ClusterUpdated {
+        cluster: ClusterRef,
+        before: ClusterSnapshot,
+    },
+    /// See [`FlattiverseEventKind::ClusterRemoved`].
+    ClusterRemoved { cluster: ClusterRef },
+    /// See [`FlattiverseEventKind::TournamentCreated`].
+    TournamentCreated { tournament: TournamentRecord },
+    /// See [`FlattiverseEventKind::TournamentUpdated`].
+    TournamentUpdated {
+        old_tournament: TournamentRecord,
+        new_tournament: TournamentRecord,
+    },
+    /// See [`FlattiverseEventKind::TournamentRemoved`].
+    TournamentRemoved { tournament: TournamentRecord },
+    /// See [`FlattiverseEventKind::TournamentMessage`].
+    TournamentMessage { message: String },
+    /// See [`FlattiverseEventKind::PowerUpCollected`].
+    PowerUpCollected {
+        controllable: ControllableRef,
+        power_up_kind: UnitKind,
+        power_up_name: String,
+        amount: f32,
+        applied_amount: f32,
+    },
+    /// See [`FlattiverseEventKind::ArmorSubsystem`].
+    ArmorSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        reduction: f32,
+        blocked_direct_damage_this_tick: f32,
+        blocked_radiation_damage_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::BatterySubsystem`].
+    BatterySubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current: f32,
+        consumed_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::CargoSubsystem`].
+    CargoSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current_metal: f32,
+        current_carbon: f32,
+        current_hydrogen: f32,
+        current_silicon: f32,
+        current_nebula: f32,
+        nebula_hue: f32,
+    },
+    /// See [`FlattiverseEventKind::EnergyCellSubsystem`].
+    EnergyCellSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        collected_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicScannerSubsystem`].
+    DynamicScannerSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        active: bool,
+        current_width: f32,
+        current_length: f32,
+        current_angle: f32,
+        target_width: f32,
+        target_length: f32,
+        target_angle: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::ClassicShipEngineSubsystem`].
+    ClassicShipEngineSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current: Vector,
+        target: Vector,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::HullSubsystem`].
+    HullSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current: f32,
+    },
+    /// See [`FlattiverseEventKind::NebulaCollectorSubsystem`].
+    NebulaCollectorSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+        collected_this_tick: f32,
+        collected_hue_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::ClassicRailgunSubsystem`].
+    ClassicRailgunSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        direction: RailgunDirection,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::ModernRailgunSubsystem`].
+    ModernRailgunSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        direction: RailgunDirection,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::RepairSubsystem`].
+    RepairSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+        repaired_hull_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::ResourceMinerSubsystem`].
+    ResourceMinerSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+        mined_metal_this_tick: f32,
+        mined_carbon_this_tick: f32,
+        mined_hydrogen_this_tick: f32,
+        mined_silicon_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::ShieldSubsystem`].
+    ShieldSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current: f32,
+        active: bool,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicShotFabricatorSubsystem`].
+    DynamicShotFabricatorSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        active: bool,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicInterceptorFabricatorSubsystem`].
+    DynamicInterceptorFabricatorSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        active: bool,
+        rate: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicShotLauncherSubsystem`].
+    DynamicShotLauncherSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        relative_movement: Vector,
+        ticks: u16,
+        load: f32,
+        damage: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicInterceptorLauncherSubsystem`].
+    DynamicInterceptorLauncherSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        relative_movement: Vector,
+        ticks: u16,
+        load: f32,
+        damage: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicShotMagazineSubsystem`].
+    DynamicShotMagazineSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current_shots: f32,
+    },
+    /// See [`FlattiverseEventKind::DynamicInterceptorMagazineSubsystem`].
+    DynamicInterceptorMagazineSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current_shots: f32,
+    },
+    /// See [`FlattiverseEventKind::ModernShipEngineSubsystem`].
+    ModernShipEngineSubsystem {
+        controllable: ControllableRef,
+        slot: SubsystemSlot,
+        status: SubsystemStatus,
+        current_thrust: f32,
+        target_thrust: f32,
+        consumed_energy_this_tick: f32,
+        consumed_ions_this_tick: f32,
+        consumed_neutrinos_this_tick: f32,
+    },
+    /// See [`FlattiverseEventKind::CompiledWithMessage`].
+    CompiledWithMessage {
+        max_players_supported: u8,
+        symbol: Arc<String>,
+        message: String,
+    },
+    /// See [`FlattiverseEventKind::EnvironmentDamage`].
+    EnvironmentDamage {
+        controllable: ControllableRef,
+        heat: f32,
+        heat_energy_cost: f32,
+        heat_energy_overflow: f32,
+        radiation: f32,
+        radiation_damage_before_armor: f32,
+        armor_blocked_damage: f32,
+        hull_damage: f32,
+    },
+    /// See [`FlattiverseEventKind::PingMeasured`].
+    PingMeasured { ping_ms: f64 },
+    /// See [`FlattiverseEventKind::EventsLagged`].
+    EventsLagged { dropped: u64 },
+    /// See [`FlattiverseEventKind::RespondedToPingMeasurement`].
+    RespondedToPingMeasurement { challenge: u16 },
+    /// See [`FlattiverseEventKind::PlayerUpdated`].
+    PlayerUpdated { player: PlayerRef },
+}
+
+impl From<&FlattiverseEventKind> for EventRecordKind {
+    fn from(kind: &FlattiverseEventKind) -> Self {
+        match kind {
+            FlattiverseEventKind::PlayerJoined { player } => Self::PlayerJoined {
+                player: PlayerRef::from(&**player),
+            },
+            FlattiverseEventKind::PlayerScoreUpdated { player, before } => {
+                Self::PlayerScoreUpdated {
+                    player: PlayerRef::from(&**player),
+                    before: ScoreRecord::from(before),
+                }
+            }
+            FlattiverseEventKind::PlayerDisconnected { player } => Self::PlayerDisconnected {
+                player: PlayerRef::from(&**player),
+            },
+            FlattiverseEventKind::PlayerParted { player } => Self::PlayerParted {
+                player: PlayerRef::from(&**player),
+            },
+            FlattiverseEventKind::ControllableInfoRegistered {
+                player,
+                controllable,
+            } => Self::ControllableInfoRegistered {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+            },
+            FlattiverseEventKind::ControllableInfoContinued {
+                player,
+                controllable,
+            } => Self::ControllableInfoContinued {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+            },
+            FlattiverseEventKind::ControllableInfoDestroyed {
+                player,
+                controllable,
+                reason,
+            } => Self::ControllableInfoDestroyed {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+                reason: *reason,
+            },
+            FlattiverseEventKind::ControllableInfoDestroyedByNeutralCollision {
+                player,
+                controllable,
+                reason,
+                colliders_kind,
+                colliders_name,
+            } => Self::ControllableInfoDestroyedByNeutralCollision {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+                reason: *reason,
+                colliders_kind: *colliders_kind,
+                colliders_name: colliders_name.clone(),
+            },
+            FlattiverseEventKind::ControllableInfoDestroyedByPlayerUnit {
+                player,
+                controllable,
+                reason,
+                destroyed_unit,
+                destroyer_player,
+            } => Self::ControllableInfoDestroyedByPlayerUnit {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+                reason: *reason,
+                destroyed_unit: ControllableInfoRef::from(&**destroyed_unit),
+                destroyer_player: PlayerRef::from(&**destroyer_player),
+            },
+            FlattiverseEventKind::ControllableInfoScoreUpdated {
+                player,
+                controllable,
+                before,
+            } => Self::ControllableInfoScoreUpdated {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+                before: ScoreRecord::from(before),
+            },
+            FlattiverseEventKind::ControllableInfoClosed {
+                player,
+                controllable,
+            } => Self::ControllableInfoClosed {
+                player: PlayerRef::from(&**player),
+                controllable: ControllableInfoRef::from(&**controllable),
+            },
+            FlattiverseEventKind::UnitAppeared { unit } => Self::UnitAppeared {
+                unit: UnitRecord::from(&**unit),
+            },
+            FlattiverseEventKind::UnitUpdated { unit, change } => Self::UnitUpdated {
+                unit: UnitRecord::from(&**unit),
+                change: change.clone(),
+            },
+            FlattiverseEventKind::UnitRemoved { unit } => Self::UnitRemoved {
+                unit: UnitRecord::from(&**unit),
+            },
+            FlattiverseEventKind::UnitAlteredByAdmin { cluster, name } => {
+                Self::UnitAlteredByAdmin {
+                    cluster: *cluster,
+                    name: name.clone(),
+                }
+            }
+            FlattiverseEventKind::FlagScoredChat {
+                player,
+                controllable_info,
+                flag_team,
+                flag_name,
+            } => Self::FlagScoredChat {
+                player: PlayerRef::from(&**player),
+                controllable_info: ControllableInfoRef::from(&**controllable_info),
+                flag_team: TeamRef::from(&**flag_team),
+                flag_name: flag_name.clone(),
+            },
+            FlattiverseEventKind::DominationPointScoredChat {
+                team,
+                domination_point_name,
+            } => Self::DominationPointScoredChat {
+                team: TeamRef::from(&**team),
+                domination_point_name: domination_point_name.clone(),
+            },
+            FlattiverseEventKind::OwnFlagHitChat {
+                player,
+                controllable_info,
+                flag_team,
+                flag_name,
+            } => Self::OwnFlagHitChat {
+                player: PlayerRef::from(&**player),
+                controllable_info: ControllableInfoRef::from(&**controllable_info),
+                flag_team: TeamRef::from(&**flag_team),
+                flag_name: flag_name.clone(),
+            },
+            FlattiverseEventKind::GalaxyChat {
+                player,
+                destination,
+                message,
+            } => Self::GalaxyChat {
+                player: PlayerRef::from(&**player),
+                destination: destination.name().to_string(),
+                message: message.clone(),
+            },
+            FlattiverseEventKind::TeamChat {
+                player,
+                destination,
+                message,
+            } => Self::TeamChat {
+                player: PlayerRef::from(&**player),
+                destination: PlayerRef::from(&**destination),
+                message: message.clone(),
+            },
+            FlattiverseEventKind::PlayerChat {
+                player,
+                destination,
+                message,
+            } => Self::PlayerChat {
+                player: PlayerRef::from(&**player),
+                destination: PlayerRef::from(&**destination),
+                message: message.clone(),
+            },
+            FlattiverseEventKind::PlayerBinaryChat {
+                player,
+                destination,
+                message,
+            } => Self::PlayerBinaryChat {
+                player: PlayerRef::from(&**player),
+                destination: PlayerRef::from(&**destination),
+                message: message.clone(),
+            },
+            FlattiverseEventKind::MissionTargetHitChat {
+                player,
+                controllable_info,
+                mission_target_sequence,
+            } => Self::MissionTargetHitChat {
+                player: PlayerRef::from(&**player),
+                controllable_info: ControllableInfoRef::from(&**controllable_info),
+                mission_target_sequence: *mission_target_sequence,
+            },
+            FlattiverseEventKind::FlagReactivatedChat {
+                flag_team,
+                flag_name,
+            } => Self::FlagReactivatedChat {
+                flag_team: TeamRef::from(&**flag_team),
+                flag_name: flag_name.clone(),
+            },
+            FlattiverseEventKind::SystemMessage { message } => Self::SystemMessage {
+                message: message.clone(),
+            },
+            FlattiverseEventKind::MotdMessage { message } => Self::MotdMessage {
+                message: message.clone(),
+            },
+            FlattiverseEventKind::GateSwitched {
+                cluster,
+                invoker_player,
+                invoker_controllable_info,
+                switch_name,
+                gates,
+            } => Self::GateSwitched {
+                cluster: ClusterRef::from(&**cluster),
+                invoker_player: invoker_player.as_deref().map(PlayerRef::from),
+                invoker_controllable_info: invoker_controllable_info
+                    .as_deref()
+                    .map(ControllableInfoRef::from),
+                switch_name: switch_name.clone(),
+                gates: gates.clone(),
+            },
+            FlattiverseEventKind::GateRestored {
+                cluster,
+                gate_name,
+                closed,
+            } => Self::GateRestored {
+                cluster: ClusterRef::from(&**cluster),
+                gate_name: gate_name.clone(),
+                closed: *closed,
+            },
+            FlattiverseEventKind::ConnectionTerminated { message } => Self::ConnectionTerminated {
+                message: message.clone(),
+            },
+            FlattiverseEventKind::GalaxyTick {
+                tick,
+                scan_ms,
+                steady_ms,
+                gravity_ms,
+                engines_ms,
+                limit_ms,
+                movement_ms,
+                collisions_ms,
+                actions_ms,
+                visibility_ms,
+                total_ms,
+                remaining_static_segments,
+            } => Self::GalaxyTick {
+                tick: *tick,
+                scan_ms: *scan_ms,
+                steady_ms: *steady_ms,
+                gravity_ms: *gravity_ms,
+                engines_ms: *engines_ms,
+                limit_ms: *limit_ms,
+                movement_ms: *movement_ms,
+                collisions_ms: *collisions_ms,
+                actions_ms: *actions_ms,
+                visibility_ms: *visibility_ms,
+                total_ms: *total_ms,
+                remaining_static_segments: *remaining_static_segments,
+            },
+            FlattiverseEventKind::GalaxySettingsUpdated { galaxy, before } => {
+                Self::GalaxySettingsUpdated {
+                    galaxy: galaxy.name().to_string(),
+                    before: before.clone(),
+                }
+            }
+            FlattiverseEventKind::TeamCreated { team } => Self::TeamCreated {
+                team: TeamRef::from(&**team),
+            },
+            FlattiverseEventKind::TeamUpdated { team, before } => Self::TeamUpdated {
+                team: TeamRef::from(&**team),
+                before: before.clone(),
+            },
+            FlattiverseEventKind::TeamScoreUpdated { team, before } => Self::TeamScoreUpdated {
+                team: TeamRef::from(&**team),
+                before: ScoreRecord::from(before),
+            },
+            FlattiverseEventKind::TeamRemoved { team } => Self::TeamRemoved {
+                team: TeamRef::from(&**team),
+            },
+            FlattiverseEventKind::ClusterCreated { cluster } => Self::ClusterCreated {
+                cluster: ClusterRef::from(&**cluster),
+            },
+            FlattiverseEventKind::ClusterUpdated { cluster, before } => Self::ClusterUpdated {
+                cluster: ClusterRef::from(&**cluster),
+                before: before.clone(),
+            },
+            FlattiverseEventKind::ClusterRemoved { cluster } => Self::ClusterRemoved {
+                cluster: ClusterRef::from(&**cluster),
+            },
+            FlattiverseEventKind::TournamentCreated { tournament } => Self::TournamentCreated {
+                tournament: TournamentRecord::from(&**tournament),
+            },
+            FlattiverseEventKind::TournamentUpdated {
+                old_tournament,
+                new_tournament,
+            } => Self::TournamentUpdated {
+                old_tournament: TournamentRecord::from(&**old_tournament),
+                new_tournament: TournamentRecord::from(&**new_tournament),
+            },
+            FlattiverseEventKind::TournamentRemoved { tournament } => Self::TournamentRemoved {
+                tournament: TournamentRecord::from(&**tournament),
+            },
+            FlattiverseEventKind::TournamentMessage { message } => Self::TournamentMessage {
+                message: message.clone(),
+            },
+            FlattiverseEventKind::PowerUpCollected {
+                controllable,
+                power_up_kind,
+                power_up_name,
+                amount,
+                applied_amount,
+            } => Self::PowerUpCollected {
+                controllable: ControllableRef::from(&**controllable),
+                power_up_kind: *power_up_kind,
+                power_up_name: power_up_name.clone(),
+                amount: *amount,
+                applied_amount: *applied_amount,
+            },
+            FlattiverseEventKind::ArmorSubsystem {
+                controllable,
+                slot,
+                status,
+                reduction,
+                blocked_direct_damage_this_tick,
+                blocked_radiation_damage_this_tick,
+            } => Self::ArmorSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                reduction: *reduction,
+                blocked_direct_damage_this_tick: *blocked_direct_damage_this_tick,
+                blocked_radiation_damage_this_tick: *blocked_radiation_damage_this_tick,
+            },
+            FlattiverseEventKind::BatterySubsystem {
+                controllable,
+                slot,
+                status,
+                current,
+                consumed_this_tick,
+            } => Self::BatterySubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current: *current,
+                consumed_this_tick: *consumed_this_tick,
+            },
+            FlattiverseEventKind::CargoSubsystem {
+                controllable,
+                slot,
+                status,
+                current_metal,
+                current_carbon,
+                current_hydrogen,
+                current_silicon,
+                current_nebula,
+                nebula_hue,
+            } => Self::CargoSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current_metal: *current_metal,
+                current_carbon: *current_carbon,
+                current_hydrogen: *current_hydrogen,
+                current_silicon: *current_silicon,
+                current_nebula: *current_nebula,
+                nebula_hue: *nebula_hue,
+            },
+            FlattiverseEventKind::EnergyCellSubsystem {
+                controllable,
+                slot,
+                status,
+                collected_this_tick,
+            } => Self::EnergyCellSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                collected_this_tick: *collected_this_tick,
+            },
+            FlattiverseEventKind::DynamicScannerSubsystem {
+                controllable,
+                slot,
+                status,
+                active,
+                current_width,
+                current_length,
+                current_angle,
+                target_width,
+                target_length,
+                target_angle,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::DynamicScannerSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                active: *active,
+                current_width: *current_width,
+                current_length: *current_length,
+                current_angle: *current_angle,
+                target_width: *target_width,
+                target_length: *target_length,
+                target_angle: *target_angle,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::ClassicShipEngineSubsystem {
+                controllable,
+                slot,
+                status,
+                current,
+                target,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::ClassicShipEngineSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current: *current,
+                target: *target,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::HullSubsystem {
+                controllable,
+                slot,
+                status,
+                current,
+            } => Self::HullSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current: *current,
+            },
+            FlattiverseEventKind::NebulaCollectorSubsystem {
+                controllable,
+                slot,
+                status,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+                collected_this_tick,
+                collected_hue_this_tick,
+            } => Self::NebulaCollectorSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+                collected_this_tick: *collected_this_tick,
+                collected_hue_this_tick: *collected_hue_this_tick,
+            },
+            FlattiverseEventKind::ClassicRailgunSubsystem {
+                controllable,
+                slot,
+                status,
+                direction,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::ClassicRailgunSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                direction: *direction,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::ModernRailgunSubsystem {
+                controllable,
+                slot,
+                status,
+                direction,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::ModernRailgunSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                direction: *direction,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::RepairSubsystem {
+                controllable,
+                slot,
+                status,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+                repaired_hull_this_tick,
+            } => Self::RepairSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+                repaired_hull_this_tick: *repaired_hull_this_tick,
+            },
+            FlattiverseEventKind::ResourceMinerSubsystem {
+                controllable,
+                slot,
+                status,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+                mined_metal_this_tick,
+                mined_carbon_this_tick,
+                mined_hydrogen_this_tick,
+                mined_silicon_this_tick,
+            } => Self::ResourceMinerSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+                mined_metal_this_tick: *mined_metal_this_tick,
+                mined_carbon_this_tick: *mined_carbon_this_tick,
+                mined_hydrogen_this_tick: *mined_hydrogen_this_tick,
+                mined_silicon_this_tick: *mined_silicon_this_tick,
+            },
+            FlattiverseEventKind::ShieldSubsystem {
+                controllable,
+                slot,
+                status,
+                current,
+                active,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::ShieldSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current: *current,
+                active: *active,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::DynamicShotFabricatorSubsystem {
+                controllable,
+                slot,
+                status,
+                active,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::DynamicShotFabricatorSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                active: *active,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::DynamicInterceptorFabricatorSubsystem {
+                controllable,
+                slot,
+                status,
+                active,
+                rate,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::DynamicInterceptorFabricatorSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                active: *active,
+                rate: *rate,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::DynamicShotLauncherSubsystem {
+                controllable,
+                slot,
+                status,
+                relative_movement,
+                ticks,
+                load,
+                damage,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::DynamicShotLauncherSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                relative_movement: *relative_movement,
+                ticks: *ticks,
+                load: *load,
+                damage: *damage,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::DynamicInterceptorLauncherSubsystem {
+                controllable,
+                slot,
+                status,
+                relative_movement,
+                ticks,
+                load,
+                damage,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::DynamicInterceptorLauncherSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                relative_movement: *relative_movement,
+                ticks: *ticks,
+                load: *load,
+                damage: *damage,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::DynamicShotMagazineSubsystem {
+                controllable,
+                slot,
+                status,
+                current_shots,
+            } => Self::DynamicShotMagazineSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current_shots: *current_shots,
+            },
+            FlattiverseEventKind::DynamicInterceptorMagazineSubsystem {
+                controllable,
+                slot,
+                status,
+                current_shots,
+            } => Self::DynamicInterceptorMagazineSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current_shots: *current_shots,
+            },
+            FlattiverseEventKind::ModernShipEngineSubsystem {
+                controllable,
+                slot,
+                status,
+                current_thrust,
+                target_thrust,
+                consumed_energy_this_tick,
+                consumed_ions_this_tick,
+                consumed_neutrinos_this_tick,
+            } => Self::ModernShipEngineSubsystem {
+                controllable: ControllableRef::from(&**controllable),
+                slot: *slot,
+                status: *status,
+                current_thrust: *current_thrust,
+                target_thrust: *target_thrust,
+                consumed_energy_this_tick: *consumed_energy_this_tick,
+                consumed_ions_this_tick: *consumed_ions_this_tick,
+                consumed_neutrinos_this_tick: *consumed_neutrinos_this_tick,
+            },
+            FlattiverseEventKind::CompiledWithMessage {
+                max_players_supported,
+                symbol,
+                message,
+            } => Self::CompiledWithMessage {
+                max_players_supported: *max_players_supported,
+                symbol: symbol.clone(),
+                message: message.clone(),
+            },
+            FlattiverseEventKind::EnvironmentDamage {
+                controllable,
+                heat,
+                heat_energy_cost,
+                heat_energy_overflow,
+                radiation,
+                radiation_damage_before_armor,
+                armor_blocked_damage,
+                hull_damage,
+            } => Self::EnvironmentDamage {
+                controllable: ControllableRef::from(&**controllable),
+                heat: *heat,
+                heat_energy_cost: *heat_energy_cost,
+                heat_energy_overflow: *heat_energy_overflow,
+                radiation: *radiation,
+                radiation_damage_before_armor: *radiation_damage_before_armor,
+                armor_blocked_damage: *armor_blocked_damage,
+                hull_damage: *hull_damage,
+            },
+            FlattiverseEventKind::PingMeasured(duration) => Self::PingMeasured {
+                ping_ms: duration.as_secs_f64() * 1000.0,
+            },
+            FlattiverseEventKind::EventsLagged { dropped } => {
+                Self::EventsLagged { dropped: *dropped }
+            }
+            FlattiverseEventKind::RespondedToPingMeasurement { challenge } => {
+                Self::RespondedToPingMeasurement {
+                    challenge: *challenge,
+                }
+            }
+            FlattiverseEventKind::PlayerUpdated { player } => Self::PlayerUpdated {
+                player: PlayerRef::from(&**player),
+            },
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{ConsumerState, FlattiverseEvent, FlattiverseEventKind};
+use async_channel::Receiver;
+use futures_core::Stream;
+use std::fmt::{Debug, Formatter};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// [`Stream`] of [`FlattiverseEvent`]s, created by
+/// [`crate::galaxy_hierarchy::Galaxy::event_stream`] or [`crate::EventSubscription::into_stream`].
+///
+/// The last item is always a [`FlattiverseEventKind::ConnectionTerminated`] carrying the close
+/// reason, after which the stream ends. If the queue was closed without such an event, for example
+/// because it overflowed, the stream reports one on its own.
+pub struct EventStream {
+    receiver: Pin<Box<Receiver<FlattiverseEvent>>>,
+    state: ConsumerState,
+    finished: bool,
+}
+
+impl Debug for EventStream {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventStream")
+            .field("len", &self.receiver.len())
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventStream {
+    #[inline]
+    pub(crate) fn new(receiver: Receiver<FlattiverseEvent>, state: ConsumerState) -> Self {
+        Self {
+            receiver: Box::pin(receiver),
+            state,
+            finished: false,
+        }
+    }
+
+    /// The amount of events currently waiting in the underlying queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+
+    /// Whether the final [`FlattiverseEventKind::ConnectionTerminated`] has been yielded.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Groups the events into one batch per server tick, see [`TickBatches`].
+    #[inline]
+    pub fn batched_by_tick(self) -> TickBatches<Self> {
+        TickBatches::new(self)
+    }
+
+    /// Yields an [`EventTimeout`] whenever no event arrived for `duration`, see [`Timeout`].
+    #[cfg(not(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    )))]
+    #[inline]
+    pub fn timeout(self, duration: std::time::Duration) -> Timeout<Self> {
+        Timeout::new(self, duration)
+    }
+}
+
+impl Stream for EventStream {
+    type Item = FlattiverseEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        if let Some(lagged) = this.state.take_lagged_event() {
+            return Poll::Ready(Some(lagged));
+        }
+
+        let event = match ready!(this.receiver.as_mut().poll_next(cx)) {
+            Some(event) => this.state.received(event),
+            None => this.state.termination_event(),
+        };
+
+        if matches!(
+            event.kind(),
+            FlattiverseEventKind::ConnectionTerminated { .. }
+        ) {
+            this.finished = true;
+        }
+
+        Poll::Ready(Some(event))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (self.receiver.len(), None)
+        }
+    }
+}
+
+/// [`Stream`] combinator yielding all events up to and including the next
+/// [`FlattiverseEventKind::GalaxyTick`] as one batch. The events of a batch have been processed by
+/// the server in the same tick. Once the inner stream ends, the remaining events are yielded as a
+/// last, possibly tick-less batch.
+#[derive(Debug)]
+pub struct TickBatches<S> {
+    stream: S,
+    batch: Vec<FlattiverseEvent>,
+}
+
+impl<S> TickBatches<S> {
+    #[inline]
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            batch: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Yields an [`EventTimeout`] whenever no batch was completed for `duration`, see
+    /// [`Timeout`].
+    #[cfg(not(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    )))]
+    #[inline]
+    pub fn timeout(self, duration: std::time::Duration) -> Timeout<Self> {
+        Timeout::new(self, duration)
+    }
+}
+
+impl<S: Stream<Item = FlattiverseEvent> + Unpin> Stream for TickBatches<S> {
+    type Item = Vec<FlattiverseEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+                Some(event) => {
+                    let tick = matches!(event.kind(), FlattiverseEventKind::GalaxyTick { .. });
+                    this.batch.push(event);
+                    if tick {
+                        return Poll::Ready(Some(std::mem::take(&mut this.batch)));
+                    }
+                }
+                None if this.batch.is_empty() => return Poll::Ready(None),
+                None => return Poll::Ready(Some(std::mem::take(&mut this.batch))),
+            }
+        }
+    }
+}
+
+/// Error item of [`Timeout`]: the inner stream yielded nothing for the given duration.
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+#[error("No event received within {0:?}")]
+pub struct EventTimeout(pub std::time::Duration);
+
+/// [`Stream`] combinator that yields `Err(EventTimeout)` every time the inner stream stayed silent
+/// for the configured duration. The inner stream is not affected and the timer restarts after
+/// every item, so a stalled connection is reported repeatedly until it recovers or terminates.
+///
+/// Only available on desktop targets, as it relies on the tokio timer.
+#[cfg(not(all(
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+)))]
+#[derive(Debug)]
+pub struct Timeout<S> {
+    stream: S,
+    duration: std::time::Duration,
+    sleep: Pin<Box<tokio::time::Sleep>>,
+}
+
+#[cfg(not(all(
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+)))]
+impl<S> Timeout<S> {
+    #[inline]
+    pub(crate) fn new(stream: S, duration: std::time::Duration) -> Self {
+        Self {
+            stream,
+            duration,
+            sleep: Box::pin(tokio::time::sleep(duration)),
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+#[cfg(not(all(
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+)))]
+impl<S: Stream + Unpin> Stream for Timeout<S> {
+    type Item = Result<S::Item, EventTimeout>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let result = match Pin::new(&mut this.stream).poll_next(cx) {
+            Poll::Ready(Some(item)) => Ok(item),
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => {
+                ready!(std::future::Future::poll(this.sleep.as_mut(), cx));
+                Err(EventTimeout(this.duration))
+            }
+        };
+
+        let deadline = tokio::time::Instant::now() + this.duration;
+        this.sleep.as_mut().reset(deadline);
+        Poll::Ready(Some(result))
+    }
+}
@@ -0,0 +1,219 @@
+use crate::utils::Atomic;
+use crate::{
+    ConsumerState, EventFilter, EventQueue, EventQueueOptions, EventStream, FlattiverseEvent,
+    GameError,
+};
+use arc_swap::ArcSwap;
+use async_channel::{Receiver, TryRecvError};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// Independent receiver of all [`FlattiverseEvent`]s of a [`crate::galaxy_hierarchy::Galaxy`],
+/// created by [`crate::galaxy_hierarchy::Galaxy::subscribe`].
+///
+/// Each subscription has its own queue, so multiple subscriptions never steal events from each
+/// other or from [`crate::galaxy_hierarchy::Galaxy::next_event`]. If events had to be dropped
+/// because the queue was full, the next call yields a [`crate::FlattiverseEventKind::EventsLagged`]
+/// event with the amount of dropped events. Dropping the subscription unsubscribes it.
+pub struct EventSubscription {
+    receiver: Receiver<FlattiverseEvent>,
+    state: ConsumerState,
+}
+
+impl Debug for EventSubscription {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventSubscription")
+            .field("len", &self.receiver.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventSubscription {
+    /// Awaits the next [`FlattiverseEvent`] of this subscription.
+    pub async fn next_event(&self) -> Result<FlattiverseEvent, GameError> {
+        if let Some(lagged) = self.state.take_lagged_event() {
+            return Ok(lagged);
+        }
+
+        match self.receiver.recv().await {
+            Ok(event) => Ok(self.state.received(event)),
+            Err(_) => Err(self.state.closed_error("Event-Subscription closed")),
+        }
+    }
+
+    /// Returns the next [`FlattiverseEvent`] of this subscription, if available.
+    pub fn poll_next_event(&self) -> Result<Option<FlattiverseEvent>, GameError> {
+        if let Some(lagged) = self.state.take_lagged_event() {
+            return Ok(Some(lagged));
+        }
+
+        match self.receiver.try_recv() {
+            Ok(event) => Ok(Some(self.state.received(event))),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Closed) => Err(self.state.closed_error("Event-Subscription closed")),
+        }
+    }
+
+    /// The amount of events currently waiting in this subscription.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+
+    /// Turns this subscription into an [`EventStream`].
+    #[inline]
+    pub fn into_stream(self) -> EventStream {
+        EventStream::new(self.receiver, self.state)
+    }
+}
+
+struct Subscriber {
+    queue: EventQueue,
+    filter: Option<EventFilter>,
+}
+
+/// Fan-out of the events of one connection to all [`EventSubscription`]s.
+#[derive(Default)]
+pub(crate) struct EventSubscribers {
+    subscribers: ArcSwap<Vec<Arc<Subscriber>>>,
+    closed: Atomic<bool>,
+}
+
+impl Debug for EventSubscribers {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventSubscribers")
+            .field("subscribers", &self.subscribers.load().len())
+            .field("closed", &self.closed.load())
+            .finish()
+    }
+}
+
+impl EventSubscribers {
+    #[inline]
+    pub(crate) fn subscribe(
+        &self,
+        options: EventQueueOptions,
+        filter: Option<EventFilter>,
+    ) -> EventSubscription {
+        self.subscribe_replaying(options, filter, &mut std::iter::empty())
+    }
+
+    /// Like [`EventSubscribers::subscribe`], but the matching events of the backlog are queued
+    /// first.
+    pub(crate) fn subscribe_replaying(
+        &self,
+        options: EventQueueOptions,
+        filter: Option<EventFilter>,
+        backlog: &mut dyn Iterator<Item = &FlattiverseEvent>,
+    ) -> EventSubscription {
+        let (queue, receiver) = EventQueue::new(options);
+        let filter = filter.filter(|filter| !filter.is_unconstrained());
+        for event in backlog {
+            if filter.as_ref().is_none_or(|filter| filter.matches(event)) {
+                queue.push(event.clone());
+            }
+        }
+
+        let state = ConsumerState::new(Arc::clone(queue.state()));
+        let subscriber = Arc::new(Subscriber { queue, filter });
+
+        self.subscribers.rcu(|subscribers| {
+            let mut subscribers = Vec::clone(subscribers);
+            subscribers.push(Arc::clone(&subscriber));
+            subscribers
+        });
+
+        // the connection might have been closed concurrently, make sure not to miss that
+        if self.closed.load() {
+            self.close_all();
+        }
+
+        EventSubscription { receiver, state }
+    }
+
+    /// Hands the event to every subscription whose [`EventFilter`] accepts it. Subscriptions which
+    /// have been dropped or disconnected on overflow are removed afterward.
+    pub(crate) fn publish(&self, event: &FlattiverseEvent) {
+        let subscribers = self.subscribers.load();
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let mut any_closed = false;
+        for subscriber in subscribers.iter() {
+            if let Some(filter) = &subscriber.filter {
+                if !filter.matches(event) {
+                    continue;
+                }
+            }
+
+            any_closed |= !subscriber.queue.push(event.clone());
+        }
+
+        if any_closed {
+            self.subscribers.rcu(|subscribers| {
+                subscribers
+                    .iter()
+                    .filter(|subscriber| !subscriber.queue.is_closed())
+                    .cloned()
+                    .collect::<Vec<_>>()
+            });
+        }
+    }
+
+    /// Hands the final event to every subscription regardless of its capacity and
+    /// [`EventFilter`], then closes all of them. They still yield their queued events before
+    /// reporting the termination.
+    pub(crate) fn terminate(&self, event: &FlattiverseEvent) {
+        self.closed.store(true);
+        for subscriber in self.subscribers.swap(Arc::default()).iter() {
+            subscriber.queue.push_unbounded(event.clone());
+            subscriber.queue.close();
+        }
+    }
+
+    /// Closes all subscriptions. They still yield their queued events before reporting the
+    /// termination.
+    pub(crate) fn close_all(&self) {
+        self.closed.store(true);
+        for subscriber in self.subscribers.swap(Arc::default()).iter() {
+            subscriber.queue.close();
+        }
+    }
+
+    /// Whether no subscription with [`crate::OverflowPolicy::Block`] is full.
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) fn has_space(&self) -> bool {
+        self.subscribers
+            .load()
+            .iter()
+            .all(|subscriber| subscriber.queue.has_space())
+    }
+
+    /// Waits until no subscription with [`crate::OverflowPolicy::Block`] is full anymore.
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) async fn wait_for_space(&self) {
+        for subscriber in self.subscribers.load().iter() {
+            subscriber.queue.wait_for_space().await;
+        }
+    }
+}
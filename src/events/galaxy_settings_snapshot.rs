@@ -2,7 +2,7 @@ use crate::galaxy_hierarchy::{Galaxy, GameMode};
 use std::sync::Arc;
 
 /// Immutable snapshot of all server-driven galaxy setting values mirrored by the connector.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct GalaxySettingsSnapshot {
     /// Active game mode.
     pub game_mode: GameMode,
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 /// Final state of one gate after a switch action.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct GateStateChange {
     pub(crate) gate_name: String,
     pub(crate) closed: bool,
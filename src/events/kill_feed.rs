@@ -0,0 +1,117 @@
+use crate::galaxy_hierarchy::{ControllableInfo, Player};
+use crate::unit::UnitKind;
+use crate::{FlattiverseEvent, FlattiverseEventKind, PlayerUnitDestroyedReason};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Who destroyed a controllable, see [`KillFeedEntry`].
+#[derive(Debug, Clone)]
+pub enum Killer {
+    /// Destroyed by the controllable of a player.
+    Player {
+        player: Arc<Player>,
+        controllable: Arc<ControllableInfo>,
+    },
+    /// Destroyed by colliding with a neutral unit.
+    Neutral { kind: UnitKind, name: String },
+}
+
+/// One death of a controllable, derived from the `ControllableInfoDestroyed*` events.
+#[derive(Debug, Clone)]
+pub struct KillFeedEntry {
+    timestamp: SystemTime,
+    tick: Option<u32>,
+    victim_player: Arc<Player>,
+    victim: Arc<ControllableInfo>,
+    killer: Option<Killer>,
+    reason: PlayerUnitDestroyedReason,
+}
+
+impl KillFeedEntry {
+    /// Creates the entry of a [`FlattiverseEventKind::ControllableInfoDestroyed`],
+    /// [`FlattiverseEventKind::ControllableInfoDestroyedByNeutralCollision`] or
+    /// [`FlattiverseEventKind::ControllableInfoDestroyedByPlayerUnit`].
+    pub fn from_event(event: &FlattiverseEvent) -> Option<Self> {
+        let (player, controllable, reason, killer) = match event.kind() {
+            FlattiverseEventKind::ControllableInfoDestroyed {
+                player,
+                controllable,
+                reason,
+            } => (player, controllable, reason, None),
+            FlattiverseEventKind::ControllableInfoDestroyedByNeutralCollision {
+                player,
+                controllable,
+                reason,
+                colliders_kind,
+                colliders_name,
+            } => (
+                player,
+                controllable,
+                reason,
+                Some(Killer::Neutral {
+                    kind: *colliders_kind,
+                    name: colliders_name.clone(),
+                }),
+            ),
+            FlattiverseEventKind::ControllableInfoDestroyedByPlayerUnit {
+                player,
+                controllable,
+                reason,
+                destroyed_unit,
+                destroyer_player,
+            } => (
+                player,
+                controllable,
+                reason,
+                Some(Killer::Player {
+                    player: Arc::clone(destroyer_player),
+                    controllable: Arc::clone(destroyed_unit),
+                }),
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            timestamp: event.timestamp(),
+            tick: event.tick(),
+            victim_player: Arc::clone(player),
+            victim: Arc::clone(controllable),
+            killer,
+            reason: *reason,
+        })
+    }
+
+    #[inline]
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// See [`FlattiverseEvent::tick`].
+    #[inline]
+    pub fn tick(&self) -> Option<u32> {
+        self.tick
+    }
+
+    /// The owner of the destroyed controllable.
+    #[inline]
+    pub fn victim_player(&self) -> &Arc<Player> {
+        &self.victim_player
+    }
+
+    /// The destroyed controllable.
+    #[inline]
+    pub fn victim(&self) -> &Arc<ControllableInfo> {
+        &self.victim
+    }
+
+    /// `None` if the controllable was not destroyed by another unit, like on self-destruction.
+    #[inline]
+    pub fn killer(&self) -> Option<&Killer> {
+        self.killer.as_ref()
+    }
+
+    #[inline]
+    pub fn reason(&self) -> PlayerUnitDestroyedReason {
+        self.reason
+    }
+}
@@ -13,11 +13,48 @@ pub use galaxy_settings_snapshot::*;
 mod gate_state_change;
 pub use gate_state_change::*;
 
+mod event_queue;
+pub(crate) use event_queue::{ConsumerState, EventQueue};
+pub use event_queue::{EventQueueOptions, OverflowPolicy};
+
+mod event_subscription;
+pub use event_subscription::*;
+
+mod event_stream;
+pub use event_stream::*;
+
+mod event_record;
+pub use event_record::*;
+
+#[cfg(feature = "json")]
+mod event_logger;
+#[cfg(feature = "json")]
+pub use event_logger::*;
+
+mod tick_frame;
+pub use tick_frame::*;
+
+mod event_category;
+pub use event_category::*;
+
+mod event_filter;
+pub use event_filter::*;
+
+mod event_handler;
+pub use event_handler::*;
+
+mod event_history;
+pub(crate) use event_history::EventHistory;
+pub use event_history::EventHistoryLimit;
+
+mod kill_feed;
+pub use kill_feed::*;
+
 use crate::galaxy_hierarchy::{
     Cluster, ClusterId, Controllable, ControllableInfo, Galaxy, Player, RailgunDirection, Score,
     Team, Tournament,
 };
-use crate::unit::{Unit, UnitKind};
+use crate::unit::{Unit, UnitChange, UnitKind};
 use crate::{SubsystemSlot, SubsystemStatus, Vector};
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
@@ -28,30 +65,75 @@ struct Inner {
     kind: FlattiverseEventKind,
 }
 
-#[repr(transparent)]
+/// The tick is kept next to the shared part, so an event can be stamped even if it has already
+/// been cloned.
 #[derive(Clone)]
-pub struct FlattiverseEvent(Arc<Inner>);
+pub struct FlattiverseEvent {
+    tick: Option<u32>,
+    inner: Arc<Inner>,
+}
 
 impl FlattiverseEvent {
     /// [`SystemTime`] timestamp when this event instance was created inside the connector.
     /// This is a local connector timestamp, not the authoritative server tick time.
     #[inline]
     pub fn timestamp(&self) -> SystemTime {
-        self.0.stamp
+        self.inner.stamp
+    }
+
+    /// The server tick this event belongs to: the number of the
+    /// [`FlattiverseEventKind::GalaxyTick`] which closes it, the same as [`TickFrame::tick`]. A
+    /// [`FlattiverseEventKind::GalaxyTick`] belongs to its own tick. `None` for events raised
+    /// before the first tick after connecting, as the server does not tell the tick in progress.
+    #[inline]
+    pub fn tick(&self) -> Option<u32> {
+        self.tick
+    }
+
+    /// Records the tick the event belongs to.
+    #[inline]
+    pub(crate) fn stamp_tick(&mut self, tick: Option<u32>) {
+        self.tick = tick;
     }
 
     /// Connector-side event classification used for event dispatch and switch statements.
     #[inline]
     pub fn kind(&self) -> &FlattiverseEventKind {
-        &self.0.kind
+        &self.inner.kind
+    }
+
+    /// Combines two [`FlattiverseEventKind::UnitUpdated`] of the same unit into one which reports
+    /// the changes of both. It is stamped like the `later` event. `None` if one of them is not a
+    /// unit update.
+    pub(crate) fn merged_unit_update(&self, later: &FlattiverseEvent) -> Option<FlattiverseEvent> {
+        match (self.kind(), later.kind()) {
+            (
+                FlattiverseEventKind::UnitUpdated { change, .. },
+                FlattiverseEventKind::UnitUpdated {
+                    unit,
+                    change: later_change,
+                },
+            ) => Some(Self {
+                tick: later.tick,
+                inner: Arc::new(Inner {
+                    stamp: later.inner.stamp,
+                    kind: FlattiverseEventKind::UnitUpdated {
+                        unit: Arc::clone(unit),
+                        change: change.merged(later_change),
+                    },
+                }),
+            }),
+            _ => None,
+        }
     }
 }
 
 impl Debug for FlattiverseEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FlattiverseEvent")
-            .field("stamp", &self.0.stamp)
-            .field("kind", &self.0.kind)
+            .field("stamp", &self.inner.stamp)
+            .field("tick", &self.tick)
+            .field("kind", &self.inner.kind)
             .finish()
     }
 }
@@ -59,10 +141,13 @@ impl Debug for FlattiverseEvent {
 impl From<FlattiverseEventKind> for FlattiverseEvent {
     #[inline]
     fn from(kind: FlattiverseEventKind) -> Self {
-        Self(Arc::new(Inner {
-            stamp: crate::runtime::now(),
-            kind,
-        }))
+        Self {
+            tick: None,
+            inner: Arc::new(Inner {
+                stamp: crate::runtime::now(),
+                kind,
+            }),
+        }
     }
 }
 
@@ -105,8 +190,8 @@ impl Display for FlattiverseEvent {
             };
         }
 
-        write!(f, "{} ", crate::runtime::format_date_time(self.0.stamp))?;
-        match &self.0.kind {
+        write!(f, "{} ", crate::runtime::format_date_time(self.inner.stamp))?;
+        match &self.inner.kind {
             FlattiverseEventKind::ConnectionTerminated { message } => match message.as_ref() {
                 None => write!(f, "Connection terminated."),
                 Some(message) => write!(f, "Connection terminated: {}", message),
@@ -119,6 +204,10 @@ impl Display for FlattiverseEvent {
                 f,
                 "Ping measured: {ping:?}"
             ),
+            FlattiverseEventKind::EventsLagged { dropped } => write!(
+                f,
+                "Events lagged: {dropped} events have been dropped."
+            ),
 
             FlattiverseEventKind::TeamCreated { team } => write!(
                 f,
@@ -612,7 +701,7 @@ impl Display for FlattiverseEvent {
                     }
                 }
             }
-            FlattiverseEventKind::UnitUpdated { unit } => {
+            FlattiverseEventKind::UnitUpdated { unit, change } => {
                 let cluster = unit.cluster();
                 let cluster = &*cluster.name();
                 let kind = unit.kind();
@@ -626,6 +715,11 @@ impl Display for FlattiverseEvent {
                         let team = &*team.name();
                         write!(f, "Updated Unit in cluster {cluster:?} and with team {team:?} of Kind {kind:?} with name {name:?} on position {position:?} and with radius {radius} and gravity {gravity:.3}.")
                     }
+                }?;
+                if change.is_empty() {
+                    Ok(())
+                } else {
+                    write!(f, " Changed: {change}.")
                 }
             }
             FlattiverseEventKind::UnitRemoved { unit } => {
@@ -844,6 +938,8 @@ pub enum FlattiverseEventKind {
     UnitUpdated {
         /// Snapshot copy of the visible unit this event is about.
         unit: Arc<dyn Unit>,
+        /// The fields changed by this update with their values before and after it.
+        change: UnitChange,
     },
     /// Raised when a previously known visible unit leaves the local visibility mirror.
     UnitRemoved {
@@ -1508,6 +1604,12 @@ pub enum FlattiverseEventKind {
 
     // ---------- local events below
     PingMeasured(Duration),
+    /// Raised in place of events that had to be dropped because an event queue was full.
+    /// See [`OverflowPolicy`].
+    EventsLagged {
+        /// The amount of events dropped since the last [`FlattiverseEventKind::EventsLagged`].
+        dropped: u64,
+    },
     RespondedToPingMeasurement {
         challenge: u16,
     },
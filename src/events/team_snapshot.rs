@@ -1,7 +1,7 @@
 use crate::galaxy_hierarchy::{Team, TeamId};
 
 /// Snapshot of a team state relevant for events.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct TeamSnapshot {
     /// Team id.
     pub id: TeamId,
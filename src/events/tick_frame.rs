@@ -0,0 +1,103 @@
+use crate::network::TickGate;
+use crate::FlattiverseEvent;
+use std::sync::Arc;
+
+/// Server-side processing times of one tick, as reported by
+/// [`crate::FlattiverseEventKind::GalaxyTick`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize)]
+pub struct TickTimings {
+    pub scan_ms: f32,
+    pub steady_ms: f32,
+    pub gravity_ms: f32,
+    pub engines_ms: f32,
+    pub limit_ms: f32,
+    pub movement_ms: f32,
+    pub collisions_ms: f32,
+    pub actions_ms: f32,
+    pub visibility_ms: f32,
+    pub total_ms: f32,
+    pub remaining_static_segments: i32,
+}
+
+/// All events of one server tick, created by
+/// [`crate::galaxy_hierarchy::Galaxy::next_tick_frame`].
+///
+/// While a frame is alive, the connection does not apply any further packets, so the mirror
+/// reflects exactly the state after this tick. Drop the frame as soon as the bot is done with the
+/// tick, as the server keeps sending in the meantime and everything received is buffered.
+#[derive(Debug)]
+pub struct TickFrame {
+    tick: u32,
+    timings: TickTimings,
+    events: Vec<FlattiverseEvent>,
+    gate: Arc<TickGate>,
+}
+
+impl Drop for TickFrame {
+    #[inline]
+    fn drop(&mut self) {
+        self.gate.release(self.tick);
+    }
+}
+
+impl TickFrame {
+    #[inline]
+    pub(crate) fn new(
+        tick: u32,
+        timings: TickTimings,
+        events: Vec<FlattiverseEvent>,
+        gate: Arc<TickGate>,
+    ) -> Self {
+        Self {
+            tick,
+            timings,
+            events,
+            gate,
+        }
+    }
+
+    /// Tick number processed by the server.
+    #[inline]
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    #[inline]
+    pub fn timings(&self) -> &TickTimings {
+        &self.timings
+    }
+
+    /// The events of this tick in the order they were raised, without the closing
+    /// [`crate::FlattiverseEventKind::GalaxyTick`]. The first frame also contains the events of
+    /// the ticks applied before the lockstep started, including their
+    /// [`crate::FlattiverseEventKind::GalaxyTick`]s.
+    #[inline]
+    pub fn events(&self) -> &[FlattiverseEvent] {
+        &self.events
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &FlattiverseEvent> {
+        self.events.iter()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a TickFrame {
+    type Item = &'a FlattiverseEvent;
+    type IntoIter = std::slice::Iter<'a, FlattiverseEvent>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
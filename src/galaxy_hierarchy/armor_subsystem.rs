@@ -116,3 +116,13 @@ impl AsRef<SubsystemBase> for ArmorSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ArmorSubsystem,
+    [reduction],
+    [
+        blocked_direct_damage_this_tick,
+        blocked_radiation_damage_this_tick,
+        blocked_total_this_tick
+    ]
+);
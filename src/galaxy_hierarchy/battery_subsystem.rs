@@ -133,3 +133,5 @@ impl AsRef<SubsystemBase> for BatterySubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(BatterySubsystem, [current, maximum], [consumed_this_tick]);
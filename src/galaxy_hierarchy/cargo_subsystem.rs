@@ -242,3 +242,22 @@ impl AsRef<SubsystemBase> for CargoSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    CargoSubsystem,
+    [
+        current_metal,
+        current_carbon,
+        current_hydrogen,
+        current_silicon,
+        current_nebula
+    ],
+    [
+        maximum_metal,
+        maximum_carbon,
+        maximum_hydrogen,
+        maximum_silicon,
+        maximum_nebula,
+        nebula_hue
+    ]
+);
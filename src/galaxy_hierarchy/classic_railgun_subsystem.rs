@@ -213,3 +213,17 @@ impl AsRef<SubsystemBase> for ClassicRailgunSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ClassicRailgunSubsystem,
+    [direction],
+    [
+        projectile_speed,
+        projectile_lifetime,
+        energy_cost,
+        metal_cost,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -4,7 +4,8 @@ use crate::galaxy_hierarchy::{
     DynamicInterceptorLauncherSubsystem, DynamicInterceptorMagazineSubsystem,
     DynamicScannerSubsystem, DynamicShotFabricatorSubsystem, DynamicShotLauncherSubsystem,
     DynamicShotMagazineSubsystem, JumpDriveSubsystem, ModernShipControllable,
-    NebulaCollectorSubsystem, RailgunDirection, SubsystemBase, SubsystemExt, SystemExtIntern,
+    NebulaCollectorSubsystem, RailgunDirection, SubsystemBase, SubsystemExt, SubsystemState,
+    SystemExtIntern,
 };
 use crate::network::PacketReader;
 use crate::utils::Readable;
@@ -560,6 +561,24 @@ impl ClassicShipControllable {
         .into_iter()
     }
 
+    pub(crate) fn subsystem_states(&self) -> impl Iterator<Item = SubsystemState> {
+        [
+            SubsystemState::capture(&self.nebula_collector),
+            SubsystemState::capture(&self.engine),
+            SubsystemState::capture(&self.shot_launcher),
+            SubsystemState::capture(&self.shot_magazine),
+            SubsystemState::capture(&self.shot_fabricator),
+            SubsystemState::capture(&self.interceptor_launcher),
+            SubsystemState::capture(&self.interceptor_magazine),
+            SubsystemState::capture(&self.interceptor_fabricator),
+            SubsystemState::capture(&self.railgun),
+            SubsystemState::capture(&self.main_scanner),
+            SubsystemState::capture(&self.secondary_scanner),
+            SubsystemState::capture(&self.jump_drive),
+        ]
+        .into_iter()
+    }
+
     pub(crate) fn iter_runtime_events(&self) -> impl Iterator<Item = FlattiverseEvent> {
         [
             self.nebula_collector.create_runtime_event(),
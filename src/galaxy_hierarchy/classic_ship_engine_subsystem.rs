@@ -208,3 +208,14 @@ impl AsRef<SubsystemBase> for ClassicShipEngineSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ClassicShipEngineSubsystem,
+    [current, target],
+    [
+        maximum,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -1,17 +1,25 @@
-use crate::galaxy_hierarchy::{EditableUnitSummary, Galaxy, Identifiable, Indexer};
-use crate::unit::Unit;
-use crate::utils::Atomic;
+use crate::galaxy_hierarchy::{
+    ClusterMap, EditableUnitSummary, Galaxy, Identifiable, Indexer, KnownUnit, MapUnit,
+    MotionHistory,
+};
+use crate::unit::{
+    Explosion, MobileNpcUnit, MobileUnit, NpcUnit, PlayerUnit, PowerUp, Projectile, SteadyUnit,
+    StormWhirl, TargetUnit, Unit, UnitKind,
+};
 use crate::utils::GuardedArcStringDeref;
-use crate::{GameError, ProgressState};
+use crate::utils::{Atomic, SpatialGrid};
+use crate::{GameError, ProgressState, Vector};
 use arc_swap::ArcSwap;
 use crossbeam_skiplist::SkipMap;
 use serde::{Deserialize, Serialize};
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Mutex, RwLock, Weak};
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(
+    Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, serde::Serialize, serde::Deserialize,
+)]
 pub struct ClusterId(pub(crate) u8);
 
 impl Indexer for ClusterId {
@@ -30,6 +38,14 @@ pub struct Cluster {
     respawn: Atomic<bool>,
     active: Atomic<bool>,
     units: SkipMap<String, Arc<dyn Unit>>,
+    /// The units by position, for the proximity queries like [`Cluster::units_within`].
+    index: RwLock<SpatialGrid<Arc<dyn Unit>>>,
+    /// The units which left our scanners, see [`Cluster::known_units`].
+    remembered: SkipMap<String, KnownUnit>,
+    /// The units of an imported map which were not rediscovered yet, see [`Cluster::import_map`].
+    imported: SkipMap<String, MapUnit>,
+    /// The recent movement updates of the visible mobile units, see [`Cluster::motion_history`].
+    motion: SkipMap<String, Mutex<MotionHistory>>,
 }
 
 impl Debug for Cluster {
@@ -43,6 +59,24 @@ impl Debug for Cluster {
     }
 }
 
+impl Display for Cluster {
+    /// `"name" #id`, the alternate form adds the flags and the number of visible units.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} #{}", &*self.name(), self.id.0)?;
+        if f.alternate() {
+            write!(
+                f,
+                " start={} respawn={} active={} units={}",
+                self.start(),
+                self.respawn(),
+                self.active(),
+                self.units.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl Cluster {
     pub fn new(
         galaxy: Weak<Galaxy>,
@@ -59,6 +93,10 @@ impl Cluster {
             respawn: Atomic::from(respawn),
             active: Atomic::from(true),
             units: SkipMap::new(),
+            index: RwLock::default(),
+            remembered: SkipMap::new(),
+            imported: SkipMap::new(),
+            motion: SkipMap::new(),
         }
     }
 
@@ -74,13 +112,200 @@ impl Cluster {
     }
 
     pub(crate) fn add_unit(&self, unit: Arc<dyn Unit>) {
+        self.remembered.remove(unit.name());
+        self.imported.remove(unit.name());
+        self.reindex_unit(&unit);
         self.units.insert(unit.name().to_string(), unit);
     }
 
     pub(crate) fn remove_unit_(&self, name: &str) -> Option<Arc<dyn Unit>> {
+        self.index.write().unwrap().remove(name);
+        self.motion.remove(name);
         self.units.remove(name).map(|e| Arc::clone(e.value()))
     }
 
+    /// Keeps the removed unit in the known map. Projectiles and explosions are not remembered, as
+    /// they are gone for good.
+    pub(crate) fn remember_unit(&self, unit: Arc<dyn Unit>, tick: Option<u32>) {
+        if unit.as_projectile().is_none() && unit.as_explosion().is_none() {
+            self.remembered
+                .insert(unit.name().to_string(), KnownUnit::new(unit, false, tick));
+        }
+    }
+
+    /// The visible units together with the remembered units which left our scanners, if enabled
+    /// by [`Galaxy::set_known_map_enabled`], and the units of imported maps which were not
+    /// rediscovered yet, which are [`KnownUnit::stale`].
+    pub fn known_units(&self) -> Vec<KnownUnit> {
+        let tick = self.galaxy().tick();
+        self.iter_units()
+            .map(|unit| KnownUnit::new(unit, true, tick))
+            .chain(self.remembered.iter().map(|e| e.value().clone()))
+            .chain(
+                self.imported
+                    .iter()
+                    .map(|e| KnownUnit::imported(e.value().clone())),
+            )
+            .collect()
+    }
+
+    /// The remembered unit with the given name, `None` if the unit is visible or unknown.
+    #[inline]
+    pub fn get_remembered_unit(&self, name: &str) -> Option<KnownUnit> {
+        self.remembered.get(name).map(|e| e.value().clone())
+    }
+
+    /// Drops all remembered units of this cluster.
+    #[inline]
+    pub fn forget_remembered_units(&self) {
+        self.remembered.clear();
+    }
+
+    /// Adds the current state of the unit to its [`MotionHistory`]. Units which are not mobile are
+    /// ignored.
+    pub(crate) fn record_motion(&self, unit: &dyn Unit, tick: u32, capacity: usize) {
+        if unit.as_mobile_unit().is_some() {
+            let entry = match self.motion.get(unit.name()) {
+                Some(entry) => entry,
+                None => self
+                    .motion
+                    .get_or_insert_with(unit.name().to_string(), Default::default),
+            };
+            entry.value().lock().unwrap().record(unit, tick, capacity);
+        }
+    }
+
+    /// The recent movement updates of the visible mobile unit with the given name, if enabled by
+    /// [`Galaxy::set_motion_history_length`]. `None` if nothing was recorded for this unit.
+    #[inline]
+    pub fn motion_history(&self, name: &str) -> Option<MotionHistory> {
+        self.with_motion_history(name, MotionHistory::clone)
+    }
+
+    /// Calls `f` with the [`Cluster::motion_history`] of the unit without copying it.
+    pub(crate) fn with_motion_history<T>(
+        &self,
+        name: &str,
+        f: impl FnOnce(&MotionHistory) -> T,
+    ) -> Option<T> {
+        self.motion.get(name).map(|e| f(&e.value().lock().unwrap()))
+    }
+
+    /// Drops the motion histories of all units of this cluster.
+    #[inline]
+    pub fn forget_motion_histories(&self) {
+        self.motion.clear();
+    }
+
+    /// The steady units of the known map together with the units of an imported map which were
+    /// not rediscovered yet, to be written to disk, for example with `ClusterMap::write_to` of the
+    /// `json` feature.
+    pub fn export_map(&self) -> ClusterMap {
+        let units = self
+            .known_units()
+            .iter()
+            .filter_map(MapUnit::from_known)
+            .collect::<Vec<_>>();
+
+        ClusterMap {
+            version: ClusterMap::VERSION,
+            cluster: self.name().to_string(),
+            exported_ms: crate::runtime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            units,
+        }
+    }
+
+    /// Loads the units of a map exported in an earlier session, see
+    /// [`Galaxy::import_map`]. Units which were seen in this session are skipped, the others are
+    /// marked stale and are part of the [`Cluster::known_units`] until our scanners rediscover
+    /// them. The cluster name of the map is not checked.
+    pub fn import_map(&self, map: ClusterMap) {
+        for mut unit in map.units {
+            if !self.units.contains_key(&unit.name) && !self.remembered.contains_key(&unit.name) {
+                unit.stale = true;
+                self.imported.insert(unit.name.clone(), unit);
+            }
+        }
+    }
+
+    /// The units of imported maps which were not rediscovered yet.
+    #[inline]
+    pub fn imported_map_units(&self) -> Vec<MapUnit> {
+        self.imported.iter().map(|e| e.value().clone()).collect()
+    }
+
+    /// Drops all units of imported maps which were not rediscovered yet.
+    #[inline]
+    pub fn forget_imported_map(&self) {
+        self.imported.clear();
+    }
+
+    /// Updates the position and radius of the unit in the index, after it moved or changed.
+    pub(crate) fn reindex_unit(&self, unit: &Arc<dyn Unit>) {
+        self.index.write().unwrap().insert(
+            unit.name(),
+            unit.position(),
+            unit.radius(),
+            Arc::clone(unit),
+        );
+    }
+
+    /// The units overlapping the given circle.
+    pub fn units_within(&self, center: Vector, radius: f32) -> Vec<Arc<dyn Unit>> {
+        self.index
+            .read()
+            .unwrap()
+            .within(center, radius)
+            .into_iter()
+            .map(Arc::clone)
+            .collect()
+    }
+
+    /// The units overlapping the rectangle spanned by the two corners.
+    pub fn units_in_rect(&self, a: Vector, b: Vector) -> Vec<Arc<dyn Unit>> {
+        self.index
+            .read()
+            .unwrap()
+            .in_rect(a, b)
+            .into_iter()
+            .map(Arc::clone)
+            .collect()
+    }
+
+    /// The unit closest to the position which passes the filter, with the distance to its
+    /// surface.
+    pub fn nearest(
+        &self,
+        position: Vector,
+        mut filter: impl FnMut(&dyn Unit) -> bool,
+    ) -> Option<(Arc<dyn Unit>, f32)> {
+        self.index
+            .read()
+            .unwrap()
+            .nearest(position, |unit| filter(&**unit))
+            .map(|(unit, distance)| (Arc::clone(unit), distance))
+    }
+
+    /// Up to `k` units closest to the position which pass the filter, with the distance to their
+    /// surface, closest first.
+    pub fn k_nearest(
+        &self,
+        position: Vector,
+        k: usize,
+        mut filter: impl FnMut(&dyn Unit) -> bool,
+    ) -> Vec<(Arc<dyn Unit>, f32)> {
+        self.index
+            .read()
+            .unwrap()
+            .k_nearest(position, k, |unit| filter(&**unit))
+            .into_iter()
+            .map(|(unit, distance)| (Arc::clone(unit), distance))
+            .collect()
+    }
+
     #[inline]
     pub fn get_unit(&self, unit: &str) -> Option<Arc<dyn Unit>> {
         self.units.get(unit).map(|e| Arc::clone(e.value()))
@@ -91,6 +316,116 @@ impl Cluster {
         self.units.iter().map(|e| Arc::clone(e.value()))
     }
 
+    /// The predicted positions of all visible steady units at the given galaxy tick, see
+    /// [`SteadyUnit::position_at`]. Only units with known orbits move, so the prediction holds
+    /// many ticks ahead for planets and moons.
+    pub fn predict_steady_positions(&self, tick: u32) -> Vec<(Arc<dyn SteadyUnit>, Vector)> {
+        self.iter_steady_units()
+            .map(|unit| {
+                let position = unit.position_at(tick);
+                (unit, position)
+            })
+            .collect()
+    }
+
+    /// The units of the concrete type, like `cluster.iter_units_of::<Sun>()`.
+    #[inline]
+    pub fn iter_units_of<T: Unit>(&self) -> impl Iterator<Item = Arc<T>> + '_ {
+        self.iter_units()
+            .filter_map(|unit| unit.into_unit_of::<T>())
+    }
+
+    #[inline]
+    pub fn iter_units_of_kind(&self, kind: UnitKind) -> impl Iterator<Item = Arc<dyn Unit>> + '_ {
+        self.iter_units().filter(move |unit| unit.kind() == kind)
+    }
+
+    #[inline]
+    pub fn iter_steady_units(&self) -> impl Iterator<Item = Arc<dyn SteadyUnit>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_steady_unit())
+    }
+
+    #[inline]
+    pub fn iter_target_units(&self) -> impl Iterator<Item = Arc<dyn TargetUnit>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_target_unit())
+    }
+
+    #[inline]
+    pub fn iter_player_units(&self) -> impl Iterator<Item = Arc<dyn PlayerUnit>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_player_unit())
+    }
+
+    #[inline]
+    pub fn iter_power_ups(&self) -> impl Iterator<Item = Arc<dyn PowerUp>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_power_up())
+    }
+
+    #[inline]
+    pub fn iter_mobile_units(&self) -> impl Iterator<Item = Arc<dyn MobileUnit>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_mobile_unit())
+    }
+
+    #[inline]
+    pub fn iter_storm_whirls(&self) -> impl Iterator<Item = Arc<dyn StormWhirl>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_storm_whirl())
+    }
+
+    #[inline]
+    pub fn iter_projectiles(&self) -> impl Iterator<Item = Arc<dyn Projectile>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_projectile())
+    }
+
+    #[inline]
+    pub fn iter_explosions(&self) -> impl Iterator<Item = Arc<dyn Explosion>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_explosion())
+    }
+
+    #[inline]
+    pub fn iter_npc_units(&self) -> impl Iterator<Item = Arc<dyn NpcUnit>> + '_ {
+        self.iter_units().filter_map(|unit| unit.into_npc_unit())
+    }
+
+    #[inline]
+    pub fn iter_mobile_npc_units(&self) -> impl Iterator<Item = Arc<dyn MobileNpcUnit>> + '_ {
+        self.iter_units()
+            .filter_map(|unit| unit.into_mobile_npc_unit())
+    }
+
+    /// The first unit hit by a ray from `origin` along `direction` which passes the filter, with
+    /// the distance to where the ray hits its surface. A unit containing the origin is hit at
+    /// distance `0.0`. The unit named `ignore`, usually the one casting the ray like
+    /// `Some(controllable.name())`, is skipped.
+    ///
+    /// To only consider units which stop shots, ignoring nebulas or current fields, pass
+    /// `|unit| unit.is_solid()` as filter.
+    pub fn raycast(
+        &self,
+        origin: Vector,
+        direction: Vector,
+        max_length: f32,
+        ignore: Option<&str>,
+        mut filter: impl FnMut(&dyn Unit) -> bool,
+    ) -> Option<(Arc<dyn Unit>, f32)> {
+        self.index
+            .read()
+            .unwrap()
+            .raycast(origin, direction, max_length, |unit| {
+                ignore.is_none_or(|ignore| unit.name() != ignore) && filter(&**unit)
+            })
+            .map(|(unit, distance)| (Arc::clone(unit), distance))
+    }
+
+    /// Whether no masking unit, see [`Unit::is_masking`], lies between the two positions. The
+    /// unit named `ignore` is skipped, see [`Cluster::raycast`].
+    pub fn line_of_sight(&self, a: Vector, b: Vector, ignore: Option<&str>) -> bool {
+        let direction = b - a;
+        let length = direction.length();
+        length == 0.0
+            || self
+                .raycast(a, direction, length, ignore, |unit| unit.is_masking())
+                .is_none()
+    }
+
     /// The id within the galaxy of the cluster.
     #[inline]
     pub fn id(&self) -> ClusterId {
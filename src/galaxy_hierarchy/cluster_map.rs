@@ -0,0 +1,319 @@
+use crate::galaxy_hierarchy::{KnownUnit, KnownUnitSource};
+use crate::unit::{MeteoroidType, MoonType, Orbit, PlanetType, Unit, UnitKind};
+use crate::Vector;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use std::io::{Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The discovered steady units of a cluster, written to disk so the next session does not start
+/// with an empty map, see [`crate::galaxy_hierarchy::Cluster::export_map`] and
+/// [`crate::galaxy_hierarchy::Cluster::import_map`].
+///
+/// The map carries [`ClusterMap::VERSION`]. With the `json` feature it is written as JSON by
+/// `ClusterMap::write_to`, and `ClusterMap::read_from` rejects files of other versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterMap {
+    /// The format version of the file, [`ClusterMap::VERSION`] when exported by this connector.
+    pub version: u32,
+    /// The name of the cluster. Cluster ids may change between sessions, names do not.
+    pub cluster: String,
+    /// Milliseconds since the unix epoch when the map was exported.
+    pub exported_ms: u64,
+    pub units: Vec<MapUnit>,
+}
+
+impl ClusterMap {
+    /// The current format version.
+    pub const VERSION: u32 = 1;
+
+    /// Writes the map as JSON.
+    #[cfg(feature = "json")]
+    pub fn write_to(&self, writer: impl Write) -> std::io::Result<()> {
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Reads a map written by [`ClusterMap::write_to`]. Fails with
+    /// [`std::io::ErrorKind::InvalidData`] if the file has a different format version.
+    #[cfg(feature = "json")]
+    pub fn read_from(reader: impl Read) -> std::io::Result<Self> {
+        let map = serde_json::from_reader::<_, Self>(reader)?;
+        if map.version != Self::VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Unsupported cluster map version {}, expected {}.",
+                    map.version,
+                    Self::VERSION
+                ),
+            ));
+        }
+        Ok(map)
+    }
+
+    /// The unit with the given name.
+    #[inline]
+    pub fn get_unit(&self, name: &str) -> Option<&MapUnit> {
+        self.units.iter().find(|unit| unit.name == name)
+    }
+}
+
+/// A steady unit in a [`ClusterMap`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapUnit {
+    pub name: String,
+    pub kind: UnitKind,
+    /// The position the unit was last seen at.
+    pub position: Vector,
+    /// See [`crate::unit::SteadyUnit::configured_position`].
+    pub configured_position: Vector,
+    /// See [`crate::unit::SteadyUnit::orbiting_list`].
+    pub orbits: Vec<Orbit>,
+    pub radius: f32,
+    pub gravity: f32,
+    pub details: MapUnitDetails,
+    /// Milliseconds since the unix epoch when the unit was last seen.
+    pub last_seen_ms: u64,
+    /// The tick the unit was last seen at, `None` if unknown.
+    pub last_seen_tick: Option<u32>,
+    /// Whether the unit was out of sight when the map was exported, so the values above may be
+    /// outdated. Units of an imported map stay stale until our scanners rediscover them.
+    pub stale: bool,
+}
+
+impl MapUnit {
+    /// `None` for units which are not steady.
+    pub(crate) fn from_known(known: &KnownUnit) -> Option<Self> {
+        let unit = match known.source() {
+            KnownUnitSource::Seen(unit) => unit,
+            KnownUnitSource::Imported(unit) => return Some(unit.clone()),
+        };
+        let steady = unit.as_steady_unit()?;
+        Some(Self {
+            name: unit.name().to_string(),
+            kind: unit.kind(),
+            position: known.last_seen_position(),
+            configured_position: steady.configured_position(),
+            orbits: steady.orbiting_list().to_vec(),
+            radius: unit.radius(),
+            gravity: unit.gravity(),
+            details: MapUnitDetails::from(&**unit),
+            last_seen_ms: known
+                .last_seen()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            last_seen_tick: known.last_seen_tick(),
+            stale: !known.visible(),
+        })
+    }
+
+    /// Predicted position at the given galaxy tick, following the orbit chain like
+    /// [`crate::unit::SteadyUnit::position_at`]. Units without orbits stay where they were seen.
+    pub fn position_at(&self, tick: u32) -> Vector {
+        if self.orbits.is_empty() {
+            self.position
+        } else {
+            Orbit::chain_position(self.configured_position, &self.orbits, tick)
+        }
+    }
+
+    /// How long ago the unit was last seen, relative to `now`.
+    #[inline]
+    pub fn age_ms(&self, now: SystemTime) -> u64 {
+        (now.duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64)
+            .saturating_sub(self.last_seen_ms)
+    }
+}
+
+/// The kind specific values of a [`MapUnit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MapUnitDetails {
+    Sun {
+        energy: f32,
+        ions: f32,
+        neutrinos: f32,
+        heat: f32,
+        drain: f32,
+    },
+    BlackHole {
+        gravity_well_radius: f32,
+        gravity_well_force: f32,
+    },
+    Planet {
+        planet_type: PlanetType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    Moon {
+        moon_type: MoonType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    Meteoroid {
+        meteoroid_type: MeteoroidType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    WormHole {
+        /// `None` if the target cluster was unknown.
+        target_cluster: Option<String>,
+        target_left: f32,
+        target_top: f32,
+        target_right: f32,
+        target_bottom: f32,
+    },
+    Gate {
+        linked_id: u16,
+        default_closed: bool,
+        closed: bool,
+    },
+    Buoy {
+        message: Option<String>,
+    },
+    /// Units without values worth keeping.
+    Other,
+}
+
+impl From<&dyn Unit> for MapUnitDetails {
+    fn from(unit: &dyn Unit) -> Self {
+        if let Some(sun) = unit.as_sun() {
+            Self::Sun {
+                energy: sun.energy(),
+                ions: sun.ions(),
+                neutrinos: sun.neutrinos(),
+                heat: sun.heat(),
+                drain: sun.drain(),
+            }
+        } else if let Some(black_hole) = unit.as_black_hole() {
+            Self::BlackHole {
+                gravity_well_radius: black_hole.gravity_well_radius(),
+                gravity_well_force: black_hole.gravity_well_force(),
+            }
+        } else if let Some(planet) = unit.as_planet() {
+            Self::Planet {
+                planet_type: planet.r#type(),
+                metal: planet.metal(),
+                carbon: planet.carbon(),
+                hydrogen: planet.hydrogen(),
+                silicon: planet.silicon(),
+            }
+        } else if let Some(moon) = unit.as_moon() {
+            Self::Moon {
+                moon_type: moon.r#type(),
+                metal: moon.metal(),
+                carbon: moon.carbon(),
+                hydrogen: moon.hydrogen(),
+                silicon: moon.silicon(),
+            }
+        } else if let Some(meteoroid) = unit.as_meteoroid() {
+            Self::Meteoroid {
+                meteoroid_type: meteoroid.r#type(),
+                metal: meteoroid.metal(),
+                carbon: meteoroid.carbon(),
+                hydrogen: meteoroid.hydrogen(),
+                silicon: meteoroid.silicon(),
+            }
+        } else if let Some(worm_hole) = unit.as_worm_hole() {
+            Self::WormHole {
+                target_cluster: worm_hole
+                    .target_cluster()
+                    .upgrade()
+                    .map(|cluster| cluster.name().to_string()),
+                target_left: worm_hole.target_left(),
+                target_top: worm_hole.target_top(),
+                target_right: worm_hole.target_right(),
+                target_bottom: worm_hole.target_bottom(),
+            }
+        } else if let Some(gate) = unit.as_gate() {
+            Self::Gate {
+                linked_id: gate.linked_it(),
+                default_closed: gate.default_closed(),
+                closed: gate.closed(),
+            }
+        } else if let Some(buoy) = unit.as_buoy() {
+            Self::Buoy {
+                message: buoy.message().as_ref().map(|message| message.to_string()),
+            }
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    fn map() -> ClusterMap {
+        ClusterMap {
+            version: ClusterMap::VERSION,
+            cluster: "Universe".to_string(),
+            exported_ms: 1_700_000_000_000,
+            units: vec![MapUnit {
+                name: "Gate".to_string(),
+                kind: UnitKind::Gate,
+                position: Vector::new(10.0, -20.0),
+                configured_position: Vector::new(0.0, -20.0),
+                orbits: vec![Orbit::new(10.0, 45.0, -300)],
+                radius: 5.0,
+                gravity: 0.0,
+                details: MapUnitDetails::Gate {
+                    linked_id: 7,
+                    default_closed: true,
+                    closed: false,
+                },
+                last_seen_ms: 1_699_999_999_000,
+                last_seen_tick: Some(1234),
+                stale: true,
+            }],
+        }
+    }
+
+    fn json(map: &ClusterMap) -> Vec<u8> {
+        let mut json = Vec::new();
+        map.write_to(&mut json).unwrap();
+        json
+    }
+
+    #[test]
+    fn round_trip() {
+        let written = json(&map());
+        let read = ClusterMap::read_from(written.as_slice()).unwrap();
+
+        assert_eq!(json(&read), written);
+        assert_eq!(read.cluster, "Universe");
+        let unit = read.get_unit("Gate").unwrap();
+        assert_eq!(
+            unit.position_at(0),
+            Vector::new(0.0, -20.0) + Vector::from_angle_length(45.0, 10.0)
+        );
+        assert!(matches!(
+            unit.details,
+            MapUnitDetails::Gate {
+                linked_id: 7,
+                default_closed: true,
+                closed: false
+            }
+        ));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut map = map();
+        map.version = ClusterMap::VERSION + 1;
+
+        let error = ClusterMap::read_from(json(&map).as_slice()).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
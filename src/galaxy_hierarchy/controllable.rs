@@ -8,16 +8,17 @@ use crate::network::{InvalidArgumentKind, PacketReader};
 use crate::unit::UnitKind;
 use crate::utils::{Also, Atomic, Let, Readable};
 use crate::{
-    FlattiverseEvent, FlattiverseEventKind, GameError, GameErrorKind, SubsystemSlot,
-    SubsystemStatus, Vector,
+    EventFilter, EventQueueOptions, EventStream, FlattiverseEvent, FlattiverseEventKind, GameError,
+    GameErrorKind, SubsystemSlot, SubsystemStatus, Vector,
 };
 use arc_swap::ArcSwapWeak;
+use std::fmt::{Display, Formatter};
 use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::sync::{Arc, Weak};
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, serde::Serialize)]
 pub struct ControllableId(pub(crate) u8);
 
 impl Indexer for ControllableId {
@@ -194,6 +195,26 @@ impl Controllable {
         &self.name
     }
 
+    /// A stream of only the events concerning this controllable: its runtime and subsystem events
+    /// as well as the events of its public [`crate::galaxy_hierarchy::ControllableInfo`], like its
+    /// deaths and revivals. Uses the default [`EventQueueOptions`], see
+    /// [`Controllable::events_with`].
+    #[inline]
+    pub fn events(&self) -> EventStream {
+        self.events_with(EventQueueOptions::default())
+    }
+
+    /// Like [`Controllable::events`], but with the given buffer configuration. The stream is
+    /// independent of [`crate::galaxy_hierarchy::Galaxy::next_event`] and the streams of other
+    /// controllables, so every ship can be driven by its own task. It ends after the connection
+    /// terminated.
+    pub fn events_with(&self, options: EventQueueOptions) -> EventStream {
+        self.cluster()
+            .galaxy()
+            .events_matching_with(EventFilter::default().with_controllable(self.id), options)
+            .into_stream()
+    }
+
     /// Declared runtime unit kind that this controllable uses while it is alive in the world.
     #[inline]
     pub fn kind(&self) -> UnitKind {
@@ -857,16 +878,11 @@ impl Controllable {
     }
 
     pub(crate) fn push_runtime_events(&self, events: impl Iterator<Item = FlattiverseEvent>) {
-        match self.cluster().galaxy().connection().event_sender.upgrade() {
-            Some(sender) => {
-                for event in events {
-                    if let Err(e) = sender.try_send(event) {
-                        warn!("Failed to push event {e:?}");
-                    }
-                }
-            }
-            None => {
-                warn!("Can no longer push FlattiversEvents, Sender is gone!")
+        let galaxy = self.cluster().galaxy();
+        for event in events {
+            if !galaxy.connection().dispatch_event(event) {
+                warn!("Can no longer push FlattiversEvents, the event queue is closed!");
+                break;
             }
         }
     }
@@ -982,6 +998,55 @@ impl<T> Deref for Controls<T> {
     }
 }
 
+impl Display for Controllable {
+    /// `Kind "name" @ position hull= shield= energy=`, the alternate form adds the movement, the
+    /// cluster, the remaining batteries and the environment of this tick.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:?} @ {} hull={:.1}/{:.1} shield={:.1}/{:.1} energy={:.1}",
+            self.kind(),
+            self.name,
+            self.position(),
+            self.hull.current(),
+            self.hull.maximum(),
+            self.shield.current(),
+            self.shield.maximum(),
+            self.energy_battery.current(),
+        )?;
+        if !self.alive() {
+            f.write_str(" dead")?;
+        }
+        if f.alternate() {
+            write!(
+                f,
+                " movement={} angle={:.1} angular_velocity={:.2} ions={:.1} neutrinos={:.1} \
+                 structure_load={:.1}",
+                self.movement(),
+                self.angle(),
+                self.angular_velocity(),
+                self.ion_battery.current(),
+                self.neutrino_battery.current(),
+                self.effective_structure_load(),
+            )?;
+            if let Some(cluster) = self.cluster.load().upgrade() {
+                write!(f, " cluster={:?}", &*cluster.name())?;
+            }
+            write!(
+                f,
+                " heat={:.2} radiation={:.2} hull_damage={:.2}",
+                self.environment_heat_this_tick(),
+                self.environment_radiation_this_tick(),
+                self.environment_hull_damage_this_tick(),
+            )?;
+            if !self.active() {
+                f.write_str(" inactive")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Identifiable<ControllableId> for Controllable {
     #[inline]
     fn id(&self) -> ControllableId {
@@ -2,7 +2,9 @@ use crate::galaxy_hierarchy::{Galaxy, Indexer, Player, Score};
 use crate::utils::Atomic;
 use std::sync::{Arc, Weak};
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(
+    Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, serde::Serialize, serde::Deserialize,
+)]
 pub struct ControllableInfoId(pub(crate) u8);
 
 impl Indexer for ControllableInfoId {
@@ -196,3 +196,15 @@ impl AsRef<SubsystemBase> for DynamicInterceptorFabricatorSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    DynamicInterceptorFabricatorSubsystem,
+    [active, rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
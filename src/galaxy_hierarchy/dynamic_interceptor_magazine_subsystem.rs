@@ -75,3 +75,8 @@ impl AsRef<SubsystemBase> for DynamicInterceptorMagazineSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    DynamicInterceptorMagazineSubsystem,
+    [current_shots, maximum_shots]
+);
@@ -263,3 +263,15 @@ impl AsRef<SubsystemBase> for DynamicShotFabricatorSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    DynamicShotFabricatorSubsystem,
+    [active, rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -80,3 +80,5 @@ impl AsRef<SubsystemBase> for DynamicShotMagazineSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(DynamicShotMagazineSubsystem, [current_shots, maximum_shots]);
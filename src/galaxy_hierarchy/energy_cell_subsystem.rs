@@ -103,3 +103,5 @@ impl AsRef<SubsystemBase> for EnergyCellSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(EnergyCellSubsystem, [collected_this_tick], [efficiency]);
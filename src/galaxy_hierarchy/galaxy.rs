@@ -1,20 +1,26 @@
 use crate::galaxy_hierarchy::{
-    BuildDisclosure, ClassicShipControllable, Cluster, ClusterId, Controllable, ControllableId,
-    ControllableInfo, ControllableInfoId, Controls, Crystal, GameMode, ModernShipControllable,
-    Player, PlayerId, PlayerKind, RuntimeDisclosure, Team, TeamId, Tournament, UniversalArcHolder,
+    BuildDisclosure, ClassicShipControllable, Cluster, ClusterId, ClusterMap, Controllable,
+    ControllableId, ControllableInfo, ControllableInfoId, Controls, Crystal, GalaxySnapshot,
+    GameMode, ModernShipControllable, Player, PlayerId, PlayerKind, RuntimeDisclosure, Team,
+    TeamId, Tournament, UniversalArcHolder,
 };
-use crate::network::{ConnectError, ConnectionHandle, PacketReader};
-use crate::unit::UnitKind;
+use crate::network::{ConnectError, ConnectionDriver, ConnectionHandle, PacketReader};
+use crate::unit::{UnitFieldValues, UnitKind};
 use crate::utils::GuardedArcStringDeref;
-use crate::utils::{Also, Atomic};
+use crate::utils::{Also, Atomic, MAX_BINARY_MESSAGE_LEN};
 use crate::{
-    ClusterSnapshot, FlattiverseEvent, FlattiverseEventKind, GalaxySettingsSnapshot, GameError,
-    GameErrorKind, GateStateChange, PlayerUnitDestroyedReason, TeamSnapshot,
+    dispatch, ClusterSnapshot, ConsumerState, EventFilter, EventHandler, EventHistoryLimit,
+    EventQueueOptions, EventStream, EventSubscription, FlattiverseEvent, FlattiverseEventKind,
+    GalaxySettingsSnapshot, GameError, GameErrorKind, GateStateChange, KillFeedEntry,
+    PlayerUnitDestroyedReason, TeamSnapshot, TickFrame, TickTimings,
 };
 use arc_swap::{ArcSwap, ArcSwapOption};
 use async_channel::{Receiver, TryRecvError};
+use std::future::{poll_fn, Future};
 use std::ops::Deref;
-use std::sync::Arc;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
 use tracing::instrument;
 
 pub type EventSink = Vec<FlattiverseEvent>;
@@ -49,6 +55,9 @@ pub struct Galaxy {
     active: Atomic<bool>,
     received_compiled_with: Atomic<bool>,
     received_galaxy_settings: Atomic<bool>,
+    known_map_enabled: Atomic<bool>,
+    motion_history_length: Atomic<u16>,
+    snapshots_enabled: Atomic<bool>,
     compiled_with_max_players_supported: Atomic<u8>,
     compiled_with_symbol: ArcSwap<String>,
 
@@ -59,6 +68,9 @@ pub struct Galaxy {
 
     connection: ConnectionHandle,
     events: Receiver<FlattiverseEvent>,
+    /// Events of the tick [`Galaxy::next_tick_frame`] is currently collecting.
+    tick_frame_events: Mutex<Vec<FlattiverseEvent>>,
+    snapshot: ArcSwapOption<GalaxySnapshot>,
 
     player: Atomic<PlayerId>,
     crystals: ArcSwap<Vec<Crystal>>,
@@ -68,6 +80,16 @@ pub struct Galaxy {
     // <<< partial `tournament` ---
 }
 
+impl Drop for Galaxy {
+    #[inline]
+    fn drop(&mut self) {
+        // nobody is left to release tick frames or to drain the event queue, don't leave the
+        // connection waiting
+        self.connection.tick_gate.disable();
+        self.connection.events.close();
+    }
+}
+
 impl Galaxy {
     pub const AUTH_ANONYMOUS: &'static str =
         "0000000000000000000000000000000000000000000000000000000000000000";
@@ -120,36 +142,57 @@ impl Galaxy {
         runtime_disclosure: Option<RuntimeDisclosure>,
         build_disclosure: Option<BuildDisclosure>,
     ) -> Result<Arc<Self>, ConnectError> {
-        #[cfg(not(feature = "dev-environment"))]
-        {
-            Self::connect_to(
-                &format!(
-                    "{}{}",
-                    &Self::URI_GALAXY_DEFAULT[..Self::URI_GALAXY_DEFAULT.len() - 1],
-                    galaxy
-                ),
-                auth,
-                team,
-                runtime_disclosure,
-                build_disclosure,
-            )
-            .await
-        }
-        #[cfg(feature = "dev-environment")]
-        {
-            Self::connect_to(
-                &format!(
-                    "{}{}",
-                    &Self::URI_GALAXY_DEFAULT[..Self::URI_GALAXY_DEFAULT.len() - 4],
-                    5000 + galaxy
-                ),
-                auth,
-                team,
-                runtime_disclosure,
-                build_disclosure,
-            )
-            .await
-        }
+        Self::connect_with_executor(
+            galaxy,
+            auth,
+            team,
+            runtime_disclosure,
+            build_disclosure,
+            ConnectionDriver::spawn,
+        )
+        .await
+    }
+
+    /// Same as [`Galaxy::connect`], but hands the [`ConnectionDriver`] of the new connection to
+    /// `executor` instead of spawning it on the current tokio runtime.
+    /// See [`Galaxy::connect_to_with_executor`] for details, including the tokio reactor the driver
+    /// still requires on desktop targets.
+    #[inline]
+    pub async fn connect_with_executor(
+        galaxy: u16,
+        auth: impl Into<Option<&str>>,
+        team: impl Into<Option<&str>>,
+        runtime_disclosure: Option<RuntimeDisclosure>,
+        build_disclosure: Option<BuildDisclosure>,
+        executor: impl FnOnce(ConnectionDriver),
+    ) -> Result<Arc<Self>, ConnectError> {
+        Self::connect_to_with_executor(
+            &Self::default_uri(galaxy),
+            auth,
+            team,
+            runtime_disclosure,
+            build_disclosure,
+            executor,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "dev-environment"))]
+    fn default_uri(galaxy: u16) -> String {
+        format!(
+            "{}{}",
+            &Self::URI_GALAXY_DEFAULT[..Self::URI_GALAXY_DEFAULT.len() - 1],
+            galaxy
+        )
+    }
+
+    #[cfg(feature = "dev-environment")]
+    fn default_uri(galaxy: u16) -> String {
+        format!(
+            "{}{}",
+            &Self::URI_GALAXY_DEFAULT[..Self::URI_GALAXY_DEFAULT.len() - 4],
+            5000 + galaxy
+        )
     }
 
     /// Opens a websocket connection to a galaxy endpoint, completes the login handshake, and
@@ -182,13 +225,55 @@ impl Galaxy {
     /// This method does more than opening the socket: it waits until the server has delivered the
     /// initial state and the activation session reply. It therefore returns only after the
     /// connector is ready for normal event processing via [`Galaxy::next_event`].
-    #[instrument(level = "trace", skip(auth, team), err(Display, level = "warn"))]
+    #[inline]
     pub async fn connect_to(
         uri: &str,
         auth: impl Into<Option<&str>>,
         team: impl Into<Option<&str>>,
         runtime_disclosure: Option<RuntimeDisclosure>,
         build_disclosure: Option<BuildDisclosure>,
+    ) -> Result<Arc<Self>, ConnectError> {
+        Self::connect_to_with_executor(
+            uri,
+            auth,
+            team,
+            runtime_disclosure,
+            build_disclosure,
+            ConnectionDriver::spawn,
+        )
+        .await
+    }
+
+    /// Same as [`Galaxy::connect_to`], but lets the caller decide where the connection runs.
+    ///
+    /// * `executor` Receives the [`ConnectionDriver`] of the new connection as soon as the
+    ///   websocket is open. It may spawn the driver on any executor, for example a tokio
+    ///   `current_thread` runtime or a `LocalSet`, or stash it to poll it manually.
+    ///
+    /// # Remarks
+    ///
+    /// The login handshake is only completed while the driver is polled. A caller that polls the
+    /// driver manually therefore has to poll it concurrently with the future returned by this
+    /// method, for example via `join`, and keep polling it for as long as the [`Galaxy`] is used.
+    ///
+    /// On desktop targets the driver is not runtime neutral: the websocket is a tokio
+    /// `TcpStream` and the driver uses tokio timers and `select!`. It has to be polled inside a
+    /// tokio context with IO and time enabled, and this method itself has to be awaited in one as
+    /// well. Executors like smol or async-std work only if a tokio runtime is entered around the
+    /// driver, for example by holding the guard of `Handle::enter` of a `current_thread` runtime
+    /// that is driven on another thread.
+    #[instrument(
+        level = "trace",
+        skip(auth, team, executor),
+        err(Display, level = "warn")
+    )]
+    pub async fn connect_to_with_executor(
+        uri: &str,
+        auth: impl Into<Option<&str>>,
+        team: impl Into<Option<&str>>,
+        runtime_disclosure: Option<RuntimeDisclosure>,
+        build_disclosure: Option<BuildDisclosure>,
+        executor: impl FnOnce(ConnectionDriver),
     ) -> Result<Arc<Self>, ConnectError> {
         let mut session = None;
         let this = crate::network::connect(
@@ -224,6 +309,9 @@ impl Galaxy {
                     active: Atomic::from(true),
                     received_compiled_with: Atomic::from(false),
                     received_galaxy_settings: Atomic::from(false),
+                    known_map_enabled: Atomic::from(false),
+                    motion_history_length: Atomic::default(),
+                    snapshots_enabled: Atomic::from(true),
                     compiled_with_max_players_supported: Atomic::default(),
                     compiled_with_symbol: ArcSwap::default(),
                     teams: UniversalArcHolder::with_capacity(Self::TEAM_CAPACITY),
@@ -232,6 +320,8 @@ impl Galaxy {
                     controllables: UniversalArcHolder::with_capacity(192),
                     connection: handle,
                     events: event_receiver,
+                    tick_frame_events: Mutex::default(),
+                    snapshot: ArcSwapOption::default(),
                     player: Atomic::from(PlayerId(0)),
                     crystals: ArcSwap::default(),
                     tournament: ArcSwapOption::default(),
@@ -248,6 +338,7 @@ impl Galaxy {
                     ));
                 })
             },
+            executor,
         )
         .await?;
 
@@ -1166,8 +1257,18 @@ impl Galaxy {
 
         let cluster = self.clusters.get(cluster);
         if let Some(unit) = cluster.get_unit(&name) {
+            let before = UnitFieldValues::movement(&*unit);
             unit.update_movement(reader);
-            event!(events, UnitUpdated { unit });
+            let change = before.changes_to(UnitFieldValues::movement(&*unit));
+            cluster.reindex_unit(&unit);
+            // updates before the first tick can't be placed in time
+            if let Some(tick) = self.connection.event_tick() {
+                match usize::from(self.motion_history_length.load()) {
+                    0 => {}
+                    capacity => cluster.record_motion(&*unit, tick, capacity),
+                }
+            }
+            event!(events, UnitUpdated { unit, change });
         } else {
             error!("Failed to find unit with name {name:?}");
         }
@@ -1188,8 +1289,11 @@ impl Galaxy {
 
         let cluster = self.clusters.get(cluster);
         if let Some(unit) = cluster.get_unit(&name) {
+            let before = UnitFieldValues::state(&*unit);
             unit.update_state(reader);
-            event!(events, UnitUpdated { unit });
+            let change = before.changes_to(UnitFieldValues::state(&*unit));
+            cluster.reindex_unit(&unit);
+            event!(events, UnitUpdated { unit, change });
         } else {
             error!("Failed to find unit with name {name:?}");
         }
@@ -1221,6 +1325,9 @@ impl Galaxy {
 
         let cluster = self.clusters.get(cluster);
         if let Some(unit) = cluster.remove_unit_(&name) {
+            if self.known_map_enabled.load() {
+                cluster.remember_unit(Arc::clone(&unit), self.connection.event_tick());
+            }
             event!(events, UnitRemoved { unit });
         } else {
             error!("Failed to remove unit with name {name:?}");
@@ -1275,6 +1382,12 @@ impl Galaxy {
         remaining_static_segments: i32,
     ) -> Result<(), GameError> {
         debug!("Universe tick with #{number}");
+        self.connection.tick.store(Some(number));
+        self.connection.tick_gate.hold(number);
+        if self.snapshots_enabled.load() {
+            self.snapshot
+                .store(Some(Arc::new(GalaxySnapshot::capture(self, number))));
+        }
         event!(
             events,
             GalaxyTick {
@@ -1589,7 +1702,7 @@ impl Galaxy {
 
         let message_length = reader.read_uint16();
 
-        if message_length == 0 || message_length > 1024 {
+        if message_length == 0 || usize::from(message_length) > MAX_BINARY_MESSAGE_LEN {
             Err(GameErrorKind::InvalidData {
                 message: Some(format!(
                     "Server did send a binary player chat with invalid length {message_length}."
@@ -1644,6 +1757,81 @@ impl Galaxy {
         self.max_spectators.load()
     }
 
+    /// The number of the last [`FlattiverseEventKind::GalaxyTick`] received, `None` before the
+    /// first tick after connecting. See [`FlattiverseEvent::tick`] for the tick of an event.
+    #[inline]
+    pub fn tick(&self) -> Option<u32> {
+        self.connection.tick.load()
+    }
+
+    /// Enables or disables remembering the units which leave our scanners, see
+    /// [`Cluster::known_units`]. Disabling drops the remembered units of all clusters.
+    pub fn set_known_map_enabled(&self, enabled: bool) {
+        self.known_map_enabled.store(enabled);
+        if !enabled {
+            self.clusters
+                .iter()
+                .for_each(|cluster| cluster.forget_remembered_units());
+        }
+    }
+
+    #[inline]
+    pub fn known_map_enabled(&self) -> bool {
+        self.known_map_enabled.load()
+    }
+
+    /// Sets the number of movement updates kept per mobile unit, see [`Cluster::motion_history`].
+    /// 0 disables the recording and drops the histories of all clusters.
+    pub fn set_motion_history_length(&self, length: u16) {
+        self.motion_history_length.store(length);
+        if length == 0 {
+            self.clusters
+                .iter()
+                .for_each(|cluster| cluster.forget_motion_histories());
+        }
+    }
+
+    #[inline]
+    pub fn motion_history_length(&self) -> u16 {
+        self.motion_history_length.load()
+    }
+
+    /// Hands the map to the cluster with the same name, see [`Cluster::import_map`]. Returns the
+    /// cluster, `None` if this galaxy has no such cluster.
+    pub fn import_map(&self, map: ClusterMap) -> Option<Arc<Cluster>> {
+        let cluster = self
+            .clusters
+            .iter()
+            .find(|cluster| &*cluster.name() == map.cluster.as_str())?;
+        cluster.import_map(map);
+        Some(cluster)
+    }
+
+    /// Enables or disables capturing a [`GalaxySnapshot`] at every tick, enabled by default. As
+    /// capturing copies the whole mirror, bots which don't use snapshots may disable it. Disabling
+    /// drops the last snapshot.
+    pub fn set_snapshots_enabled(&self, enabled: bool) {
+        self.snapshots_enabled.store(enabled);
+        if !enabled {
+            self.snapshot.store(None);
+        }
+    }
+
+    #[inline]
+    pub fn snapshots_enabled(&self) -> bool {
+        self.snapshots_enabled.load()
+    }
+
+    /// The [`GalaxySnapshot`] of the last [`FlattiverseEventKind::GalaxyTick`], `None` before the
+    /// first tick after connecting or while disabled by [`Galaxy::set_snapshots_enabled`]. It is
+    /// replaced before the tick is raised as event, so while the events are consumed behind the
+    /// connection, this may already be a later tick than the one being handled, see
+    /// [`GalaxySnapshot::tick`].
+    #[inline]
+    pub fn snapshot(&self) -> Option<Arc<GalaxySnapshot>> {
+        self.snapshot.load_full()
+    }
+
     /// The expected amount of simulation ticks the galaxy advances per second.
     pub fn expected_ticks_per_second(&self) -> i32 {
         10
@@ -1719,28 +1907,285 @@ impl Galaxy {
         self.compiled_with_symbol.load_full()
     }
 
-    /// Awaits the next [`FlattiverseEvent`]
+    /// Awaits the next [`FlattiverseEvent`]. If events were dropped because the queue was full,
+    /// a [`FlattiverseEventKind::EventsLagged`] is returned first, see
+    /// [`Galaxy::set_event_queue_options`].
     pub async fn next_event(&self) -> Result<FlattiverseEvent, GameError> {
-        self.events.recv().await.map_err(|_| {
-            GameErrorKind::ConnectionTerminated {
-                reason: Some(Arc::from("Event-Receiver gone")),
-            }
-            .into()
-        })
+        let state = self.connection.events.state();
+        if let Some(lagged) = state.take_lagged_event() {
+            return Ok(lagged);
+        }
+
+        match self.events.recv().await {
+            Ok(event) => Ok(state.received(event)),
+            Err(_) => Err(state.closed_error("Event-Receiver gone")),
+        }
     }
 
     /// Returns the next [`FlattiverseEvent`], if available.
     pub fn poll_next_event(&self) -> Result<Option<FlattiverseEvent>, GameError> {
+        let state = self.connection.events.state();
+        if let Some(lagged) = state.take_lagged_event() {
+            return Ok(Some(lagged));
+        }
+
         match self.events.try_recv() {
-            Ok(event) => Ok(Some(event)),
+            Ok(event) => Ok(Some(state.received(event))),
             Err(TryRecvError::Empty) => Ok(None),
-            Err(TryRecvError::Closed) => Err(GameErrorKind::ConnectionTerminated {
-                reason: Some(Arc::from("Event-Receiver gone")),
+            Err(TryRecvError::Closed) => Err(state.closed_error("Event-Receiver gone")),
+        }
+    }
+
+    /// Changes the buffering of the queue of [`Galaxy::next_event`], which is unbounded by
+    /// default. The new options apply to all further events, already queued events are kept even
+    /// if they exceed the new capacity.
+    ///
+    /// With [`crate::OverflowPolicy::Disconnect`] an overflow terminates the connection.
+    #[inline]
+    pub fn set_event_queue_options(&self, options: EventQueueOptions) {
+        self.connection.events.set_options(options);
+    }
+
+    #[inline]
+    pub fn event_queue_options(&self) -> EventQueueOptions {
+        self.connection.events.options()
+    }
+
+    /// The amount of events currently waiting in the queue of [`Galaxy::next_event`].
+    #[inline]
+    pub fn event_queue_len(&self) -> usize {
+        self.connection.events.len()
+    }
+
+    /// Awaits the next complete tick and returns all of its events as a [`TickFrame`].
+    ///
+    /// The first call switches the connection into lockstep: after each tick it stops applying
+    /// packets until the [`TickFrame`] of that tick has been dropped, so the mirror stays exactly
+    /// at the state of the frame while it is alive. Ticks which were already applied when the
+    /// lockstep started don't get a frame of their own, their events are part of the first frame.
+    /// Call [`Galaxy::stop_tick_frames`] to leave the lockstep.
+    ///
+    /// Commands can be awaited while a frame is alive. As a reply may refer to anything the server
+    /// sent before it, the packets received until then are applied first, which moves the mirror
+    /// ahead of the frame. On wasm the connection can't be paused at all, a consumer which falls
+    /// behind gets the events of several ticks in one frame.
+    ///
+    /// This consumes the queue of [`Galaxy::next_event`]. Events which are received before the
+    /// returned future is dropped are kept for the next call, so it can be used in `select!`.
+    pub async fn next_tick_frame(&self) -> Result<TickFrame, GameError> {
+        let gate = &self.connection.tick_gate;
+        gate.enable();
+
+        loop {
+            let event = self.next_event().await?;
+            let timings = match event.kind() {
+                FlattiverseEventKind::GalaxyTick {
+                    tick,
+                    scan_ms,
+                    steady_ms,
+                    gravity_ms,
+                    engines_ms,
+                    limit_ms,
+                    movement_ms,
+                    collisions_ms,
+                    actions_ms,
+                    visibility_ms,
+                    total_ms,
+                    remaining_static_segments,
+                } => Some((
+                    *tick,
+                    TickTimings {
+                        scan_ms: *scan_ms,
+                        steady_ms: *steady_ms,
+                        gravity_ms: *gravity_ms,
+                        engines_ms: *engines_ms,
+                        limit_ms: *limit_ms,
+                        movement_ms: *movement_ms,
+                        collisions_ms: *collisions_ms,
+                        actions_ms: *actions_ms,
+                        visibility_ms: *visibility_ms,
+                        total_ms: *total_ms,
+                        remaining_static_segments: *remaining_static_segments,
+                    },
+                )),
+                FlattiverseEventKind::ConnectionTerminated { message } => {
+                    return Err(GameErrorKind::ConnectionTerminated {
+                        reason: message.as_deref().map(Arc::from),
+                    }
+                    .into());
+                }
+                _ => None,
+            };
+
+            let mut pending = self.tick_frame_events.lock().unwrap();
+            match timings {
+                // the mirror is already past this tick, so it is merged into the next frame
+                Some((tick, _)) if gate.is_enabled() && !gate.holds(tick) => pending.push(event),
+                Some((tick, timings)) => {
+                    return Ok(TickFrame::new(
+                        tick,
+                        timings,
+                        std::mem::take(&mut *pending),
+                        Arc::clone(gate),
+                    ));
+                }
+                None => pending.push(event),
             }
-            .into()),
         }
     }
 
+    /// Leaves the lockstep started by [`Galaxy::next_tick_frame`], the connection no longer waits
+    /// for [`TickFrame`]s to be dropped.
+    #[inline]
+    pub fn stop_tick_frames(&self) {
+        self.connection.tick_gate.disable();
+    }
+
+    /// Enables, changes or with `None` disables the in-memory history of recent events, which is
+    /// disabled by default. The history records every event raised from now on, independent of
+    /// whether and by whom it is consumed, see [`Galaxy::event_history`].
+    #[inline]
+    pub fn set_event_history_limit(&self, limit: Option<EventHistoryLimit>) {
+        self.connection.history.set_limit(limit);
+    }
+
+    #[inline]
+    pub fn event_history_limit(&self) -> Option<EventHistoryLimit> {
+        self.connection.history.limit()
+    }
+
+    /// The recorded events, oldest first. Empty unless enabled by
+    /// [`Galaxy::set_event_history_limit`].
+    #[inline]
+    pub fn event_history(&self) -> Vec<FlattiverseEvent> {
+        self.connection.history.events()
+    }
+
+    /// Like [`Galaxy::events_matching_with`], but the subscription starts with the matching events
+    /// of the [`Galaxy::event_history`], so a late subscriber does not miss the recent past. No
+    /// event is delivered twice or skipped in between.
+    pub fn subscribe_with_history(
+        &self,
+        filter: Option<EventFilter>,
+        options: EventQueueOptions,
+    ) -> EventSubscription {
+        self.connection.history.replay(|backlog| {
+            self.connection
+                .subscribers
+                .subscribe_replaying(options, filter, backlog)
+        })
+    }
+
+    /// The deaths of controllables contained in the [`Galaxy::event_history`], oldest first.
+    pub fn kill_feed(&self) -> Vec<KillFeedEntry> {
+        self.connection
+            .history
+            .events()
+            .iter()
+            .filter_map(KillFeedEntry::from_event)
+            .collect()
+    }
+
+    /// Dispatches the events of [`Galaxy::next_event`] to the [`EventHandler`] until the
+    /// connection terminates, which is returned as [`GameErrorKind::ConnectionTerminated`] after
+    /// [`EventHandler::on_terminated`] has been called.
+    #[inline]
+    pub async fn run(self: &Arc<Self>, handler: &mut impl EventHandler) -> Result<(), GameError> {
+        self.run_until(handler, std::future::pending()).await
+    }
+
+    /// Like [`Galaxy::run`], but returns `Ok(())` once `shutdown` completes. A handler method which
+    /// is already running is awaited first, afterward [`EventHandler::on_shutdown`] is called. No
+    /// event is lost, the remaining ones stay queued for [`Galaxy::next_event`].
+    pub async fn run_until(
+        self: &Arc<Self>,
+        handler: &mut impl EventHandler,
+        shutdown: impl Future<Output = ()>,
+    ) -> Result<(), GameError> {
+        let mut shutdown = pin!(shutdown);
+
+        loop {
+            let next = {
+                let mut next_event = pin!(self.next_event());
+                poll_fn(|cx| {
+                    if shutdown.as_mut().poll(cx).is_ready() {
+                        Poll::Ready(None)
+                    } else {
+                        next_event.as_mut().poll(cx).map(Some)
+                    }
+                })
+                .await
+            };
+
+            match next {
+                None => {
+                    handler.on_shutdown(self).await;
+                    return Ok(());
+                }
+                Some(Ok(event)) => {
+                    dispatch(handler, self, &event).await;
+                    if let FlattiverseEventKind::ConnectionTerminated { message } = event.kind() {
+                        return Err(GameErrorKind::ConnectionTerminated {
+                            reason: message.as_deref().map(Arc::from),
+                        }
+                        .into());
+                    }
+                }
+                Some(Err(e)) => {
+                    handler.on_terminated(self, Some(&e.to_string())).await;
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Returns the queue of [`Galaxy::next_event`] as an [`EventStream`]. Both hand out every
+    /// event only once, so an event taken by the stream is not returned by [`Galaxy::next_event`]
+    /// and vice versa. Use [`EventSubscription::into_stream`] for an independent stream.
+    #[inline]
+    pub fn event_stream(&self) -> EventStream {
+        EventStream::new(
+            self.events.clone(),
+            ConsumerState::new(Arc::clone(self.connection.events.state())),
+        )
+    }
+
+    /// Creates an [`EventSubscription`] with the default [`EventQueueOptions`], see
+    /// [`Galaxy::subscribe_with`].
+    #[inline]
+    pub fn subscribe(&self) -> EventSubscription {
+        self.subscribe_with(EventQueueOptions::default())
+    }
+
+    /// Creates an independent [`EventSubscription`] which receives every [`FlattiverseEvent`]
+    /// raised from now on. Use this if more than one consumer needs to see all events, as
+    /// [`Galaxy::next_event`] hands out every event only once.
+    ///
+    /// The queue of [`Galaxy::next_event`] keeps being filled regardless of any subscription.
+    #[inline]
+    pub fn subscribe_with(&self, options: EventQueueOptions) -> EventSubscription {
+        self.connection.subscribers.subscribe(options, None)
+    }
+
+    /// Creates an [`EventSubscription`] with the default [`EventQueueOptions`] which only
+    /// receives the events accepted by the given [`EventFilter`], see
+    /// [`Galaxy::events_matching_with`].
+    #[inline]
+    pub fn events_matching(&self, filter: EventFilter) -> EventSubscription {
+        self.events_matching_with(filter, EventQueueOptions::default())
+    }
+
+    /// Like [`Galaxy::subscribe_with`], but the [`EventFilter`] is evaluated before an event is
+    /// queued. Rejected events neither occupy the queue nor count as dropped.
+    #[inline]
+    pub fn events_matching_with(
+        &self,
+        filter: EventFilter,
+        options: EventQueueOptions,
+    ) -> EventSubscription {
+        self.connection.subscribers.subscribe(options, Some(filter))
+    }
+
     /// Returns the underlying [`ConnectionHandle`] to the server.
     #[inline]
     pub fn connection(&self) -> &ConnectionHandle {
@@ -0,0 +1,327 @@
+use crate::galaxy_hierarchy::{
+    AsSubsystemBase, Cluster, ClusterId, Controllable, ControllableId, ControllableInfoId,
+    ControllableSpecialization, Galaxy, Player, PlayerId, PlayerKind, SubsystemExt, TeamId,
+};
+use crate::unit::{Unit, UnitKind, UnitValue};
+use crate::{SubsystemSlot, SubsystemStatus, TeamSnapshot, Vector};
+
+/// Immutable copy of the mirrored galaxy, taken when a
+/// [`crate::FlattiverseEventKind::GalaxyTick`] arrives if enabled by
+/// [`Galaxy::set_snapshots_enabled`], see [`Galaxy::snapshot`].
+///
+/// At that point all packets of the tick have been applied, so unlike reading the live mirror,
+/// the values of a snapshot are consistent with each other: a position always fits the movement,
+/// and a subsystem is never half updated. Snapshots are shared as [`std::sync::Arc`] and can be kept and
+/// read from any thread for a whole planning step.
+#[derive(Debug, Clone)]
+pub struct GalaxySnapshot {
+    /// The tick this snapshot was taken at.
+    pub tick: u32,
+    pub teams: Vec<TeamSnapshot>,
+    pub clusters: Vec<ClusterState>,
+    pub players: Vec<PlayerState>,
+    /// The controllables of the connected player.
+    pub controllables: Vec<ControllableState>,
+}
+
+impl GalaxySnapshot {
+    pub(crate) fn capture(galaxy: &Galaxy, tick: u32) -> Self {
+        Self {
+            tick,
+            teams: galaxy
+                .iter_teams()
+                .map(|team| TeamSnapshot::from(&*team))
+                .collect(),
+            clusters: galaxy
+                .iter_clusters()
+                .map(|cluster| ClusterState::from(&*cluster))
+                .collect(),
+            players: galaxy
+                .iter_players()
+                .map(|player| PlayerState::from(&*player))
+                .collect(),
+            controllables: galaxy
+                .iter_controllables()
+                .map(|controllable| ControllableState::from(&*controllable))
+                .collect(),
+        }
+    }
+
+    #[inline]
+    pub fn get_team(&self, id: TeamId) -> Option<&TeamSnapshot> {
+        self.teams.iter().find(|team| team.id == id)
+    }
+
+    #[inline]
+    pub fn get_cluster(&self, id: ClusterId) -> Option<&ClusterState> {
+        self.clusters.iter().find(|cluster| cluster.id == id)
+    }
+
+    #[inline]
+    pub fn get_player(&self, id: PlayerId) -> Option<&PlayerState> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    #[inline]
+    pub fn get_controllable(&self, id: ControllableId) -> Option<&ControllableState> {
+        self.controllables
+            .iter()
+            .find(|controllable| controllable.id == id)
+    }
+
+    /// All units of all clusters.
+    #[inline]
+    pub fn iter_units(&self) -> impl Iterator<Item = &UnitState> {
+        self.clusters
+            .iter()
+            .flat_map(|cluster| cluster.units.iter())
+    }
+}
+
+/// A cluster and its units in a [`GalaxySnapshot`].
+#[derive(Debug, Clone)]
+pub struct ClusterState {
+    pub id: ClusterId,
+    pub name: String,
+    pub start: bool,
+    pub respawn: bool,
+    /// The units of the cluster which were visible at the tick.
+    pub units: Vec<UnitState>,
+}
+
+impl ClusterState {
+    #[inline]
+    pub fn get_unit(&self, name: &str) -> Option<&UnitState> {
+        self.units.iter().find(|unit| unit.name == name)
+    }
+}
+
+impl From<&Cluster> for ClusterState {
+    fn from(cluster: &Cluster) -> Self {
+        Self {
+            id: cluster.id(),
+            name: cluster.name().to_string(),
+            start: cluster.start(),
+            respawn: cluster.respawn(),
+            units: cluster
+                .iter_units()
+                .map(|unit| UnitState::from(&*unit))
+                .collect(),
+        }
+    }
+}
+
+/// The common state of a [`Unit`] in a [`GalaxySnapshot`].
+#[derive(Debug, Clone)]
+pub struct UnitState {
+    pub name: String,
+    pub kind: UnitKind,
+    pub cluster: ClusterId,
+    pub position: Vector,
+    pub movement: Vector,
+    pub angle: f32,
+    pub radius: f32,
+    pub gravity: f32,
+    /// `None` for units without a team.
+    pub team: Option<TeamId>,
+    /// The owner, only set for player units.
+    pub player: Option<PlayerId>,
+    /// The controllable entry, only set for player units.
+    pub controllable: Option<ControllableInfoId>,
+}
+
+impl From<&dyn Unit> for UnitState {
+    fn from(unit: &dyn Unit) -> Self {
+        let player_unit = unit.as_player_unit();
+        Self {
+            name: unit.name().to_string(),
+            kind: unit.kind(),
+            cluster: unit.cluster().id(),
+            position: unit.position(),
+            movement: unit.movement(),
+            angle: unit.angle(),
+            radius: unit.radius(),
+            gravity: unit.gravity(),
+            team: unit.team().upgrade().map(|team| team.id()),
+            player: player_unit.map(|unit| unit.player().id()),
+            controllable: player_unit.map(|unit| unit.controllable_info().id()),
+        }
+    }
+}
+
+/// A player in a [`GalaxySnapshot`].
+#[derive(Debug, Clone)]
+pub struct PlayerState {
+    pub id: PlayerId,
+    pub name: String,
+    pub kind: PlayerKind,
+    /// `None` if the team is already gone.
+    pub team: Option<TeamId>,
+    pub disconnected: bool,
+    pub admin: bool,
+    pub rank: i32,
+    pub ping: f32,
+    /// The controllable entries of the player which are alive.
+    pub alive_controllables: Vec<ControllableInfoId>,
+}
+
+impl From<&Player> for PlayerState {
+    fn from(player: &Player) -> Self {
+        Self {
+            id: player.id(),
+            name: player.name().to_string(),
+            kind: player.kind(),
+            team: player.team_weak().upgrade().map(|team| team.id()),
+            disconnected: player.disconnected(),
+            admin: player.admin(),
+            rank: player.rank(),
+            ping: player.ping(),
+            alive_controllables: player
+                .iter_controllable_infos()
+                .filter(|info| info.alive())
+                .map(|info| info.id())
+                .collect(),
+        }
+    }
+}
+
+/// A controllable of the connected player in a [`GalaxySnapshot`].
+#[derive(Debug, Clone)]
+pub struct ControllableState {
+    pub id: ControllableId,
+    pub name: String,
+    pub kind: UnitKind,
+    pub cluster: ClusterId,
+    pub alive: bool,
+    pub position: Vector,
+    pub movement: Vector,
+    pub angle: f32,
+    pub angular_velocity: f32,
+    pub effective_structure_load: f32,
+    pub hull: f32,
+    pub hull_maximum: f32,
+    pub shield: f32,
+    pub shield_maximum: f32,
+    pub shield_active: bool,
+    pub energy: f32,
+    pub energy_maximum: f32,
+    pub ions: f32,
+    pub ions_maximum: f32,
+    pub neutrinos: f32,
+    pub neutrinos_maximum: f32,
+    /// All subsystem slots of the controllable, including the missing ones.
+    pub subsystems: Vec<SubsystemState>,
+}
+
+impl ControllableState {
+    #[inline]
+    pub fn get_subsystem(&self, slot: SubsystemSlot) -> Option<&SubsystemState> {
+        self.subsystems
+            .iter()
+            .find(|subsystem| subsystem.slot == slot)
+    }
+}
+
+impl From<&Controllable> for ControllableState {
+    fn from(controllable: &Controllable) -> Self {
+        let mut subsystems = vec![
+            SubsystemState::capture(controllable.hull()),
+            SubsystemState::capture(controllable.shield()),
+            SubsystemState::capture(controllable.armor()),
+            SubsystemState::capture(controllable.repair()),
+            SubsystemState::capture(controllable.cargo()),
+            SubsystemState::capture(controllable.resource_miner()),
+            SubsystemState::capture(controllable.structure_optimizer()),
+            SubsystemState::capture(controllable.energy_battery()),
+            SubsystemState::capture(controllable.ion_battery()),
+            SubsystemState::capture(controllable.neutrino_battery()),
+            SubsystemState::capture(controllable.energy_cell()),
+            SubsystemState::capture(controllable.ion_cell()),
+            SubsystemState::capture(controllable.neutrino_cell()),
+        ];
+
+        match controllable.specialization() {
+            ControllableSpecialization::ClassicShip(ship) => {
+                subsystems.extend(ship.subsystem_states())
+            }
+            ControllableSpecialization::ModernShip(ship) => {
+                subsystems.extend(ship.subsystem_states())
+            }
+        }
+
+        Self {
+            id: controllable.id(),
+            name: controllable.name().to_string(),
+            kind: controllable.kind(),
+            cluster: controllable.cluster().id(),
+            alive: controllable.alive(),
+            position: controllable.position(),
+            movement: controllable.movement(),
+            angle: controllable.angle(),
+            angular_velocity: controllable.angular_velocity(),
+            effective_structure_load: controllable.effective_structure_load(),
+            hull: controllable.hull().current(),
+            hull_maximum: controllable.hull().maximum(),
+            shield: controllable.shield().current(),
+            shield_maximum: controllable.shield().maximum(),
+            shield_active: controllable.shield().active(),
+            energy: controllable.energy_battery().current(),
+            energy_maximum: controllable.energy_battery().maximum(),
+            ions: controllable.ion_battery().current(),
+            ions_maximum: controllable.ion_battery().maximum(),
+            neutrinos: controllable.neutrino_battery().current(),
+            neutrinos_maximum: controllable.neutrino_battery().maximum(),
+            subsystems,
+        }
+    }
+}
+
+/// A subsystem in a [`GalaxySnapshot`].
+#[derive(Debug, Clone)]
+pub struct SubsystemState {
+    pub slot: SubsystemSlot,
+    pub name: String,
+    pub exists: bool,
+    pub tier: u8,
+    pub status: SubsystemStatus,
+    /// The values specific to the kind of the subsystem, like the current and maximum of a
+    /// battery, in the order of their accessors.
+    pub values: Vec<SubsystemValue>,
+}
+
+impl SubsystemState {
+    pub(crate) fn capture<S: AsSubsystemBase + SubsystemValues + ?Sized>(subsystem: &S) -> Self {
+        let base = subsystem.as_subsystem_base();
+        Self {
+            slot: base.slot(),
+            name: base.name().to_string(),
+            exists: base.exists(),
+            tier: base.tier(),
+            status: base.status(),
+            values: subsystem.values(),
+        }
+    }
+
+    /// The value of the accessor with this name, e.g. `"current"` for
+    /// [`crate::galaxy_hierarchy::BatterySubsystem::current`].
+    #[inline]
+    pub fn value(&self, name: &str) -> Option<&UnitValue> {
+        self.values
+            .iter()
+            .find(|value| value.name == name)
+            .map(|value| &value.value)
+    }
+}
+
+/// A kind-specific value of a [`SubsystemState`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubsystemValue {
+    /// The name of the accessor of the subsystem.
+    pub name: &'static str,
+    pub value: UnitValue,
+}
+
+/// Lists the kind-specific values of a subsystem, implemented by `subsystem_fields!`.
+pub(crate) trait SubsystemValues {
+    fn values(&self) -> Vec<SubsystemValue>;
+}
@@ -10,6 +10,7 @@
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    serde::Serialize,
 )]
 pub enum GameMode {
     /// In this game mode players try to complete mission objectives.
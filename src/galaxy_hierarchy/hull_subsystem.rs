@@ -98,3 +98,5 @@ impl AsRef<SubsystemBase> for HullSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(HullSubsystem, [current, maximum]);
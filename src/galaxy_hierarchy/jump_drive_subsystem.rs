@@ -128,3 +128,13 @@ impl AsRef<SubsystemBase> for JumpDriveSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    JumpDriveSubsystem,
+    [energy_cost],
+    [
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
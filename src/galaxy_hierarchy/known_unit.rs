@@ -0,0 +1,137 @@
+use crate::galaxy_hierarchy::MapUnit;
+use crate::unit::{Unit, UnitKind};
+use crate::Vector;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A unit of the known map of a cluster, see [`crate::galaxy_hierarchy::Cluster::known_units`].
+///
+/// Either a unit currently visible to our scanners, the last state of a unit which left them, if
+/// remembering is enabled by [`crate::galaxy_hierarchy::Galaxy::set_known_map_enabled`], or a unit
+/// of an imported map which was not rediscovered yet.
+#[derive(Debug, Clone)]
+pub struct KnownUnit {
+    source: KnownUnitSource,
+    visible: bool,
+    last_seen: SystemTime,
+    last_seen_tick: Option<u32>,
+    last_seen_position: Vector,
+}
+
+/// Where the values of a [`KnownUnit`] come from.
+#[derive(Debug, Clone)]
+pub enum KnownUnitSource {
+    /// A unit seen in this session: the live unit if it is visible, otherwise the unit as it was
+    /// when it left our scanners. A remembered unit is no longer updated.
+    Seen(Arc<dyn Unit>),
+    /// A unit of a map imported by [`crate::galaxy_hierarchy::Cluster::import_map`].
+    Imported(MapUnit),
+}
+
+impl KnownUnit {
+    #[inline]
+    pub(crate) fn new(unit: Arc<dyn Unit>, visible: bool, last_seen_tick: Option<u32>) -> Self {
+        Self {
+            last_seen_position: unit.position(),
+            source: KnownUnitSource::Seen(unit),
+            visible,
+            last_seen: crate::runtime::now(),
+            last_seen_tick,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn imported(unit: MapUnit) -> Self {
+        Self {
+            last_seen_position: unit.position,
+            visible: false,
+            last_seen: UNIX_EPOCH + Duration::from_millis(unit.last_seen_ms),
+            last_seen_tick: unit.last_seen_tick,
+            source: KnownUnitSource::Imported(unit),
+        }
+    }
+
+    #[inline]
+    pub fn source(&self) -> &KnownUnitSource {
+        &self.source
+    }
+
+    /// The unit if it was seen in this session, `None` for a unit of an imported map.
+    #[inline]
+    pub fn unit(&self) -> Option<&Arc<dyn Unit>> {
+        match &self.source {
+            KnownUnitSource::Seen(unit) => Some(unit),
+            KnownUnitSource::Imported(_) => None,
+        }
+    }
+
+    /// The unit of the imported map, `None` if the unit was seen in this session.
+    #[inline]
+    pub fn map_unit(&self) -> Option<&MapUnit> {
+        match &self.source {
+            KnownUnitSource::Seen(_) => None,
+            KnownUnitSource::Imported(unit) => Some(unit),
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        match &self.source {
+            KnownUnitSource::Seen(unit) => unit.name(),
+            KnownUnitSource::Imported(unit) => &unit.name,
+        }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> UnitKind {
+        match &self.source {
+            KnownUnitSource::Seen(unit) => unit.kind(),
+            KnownUnitSource::Imported(unit) => unit.kind,
+        }
+    }
+
+    /// Whether the unit is currently visible to our scanners.
+    #[inline]
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Whether the unit stems from an imported map and has not been seen in this session, so its
+    /// values may be outdated.
+    #[inline]
+    pub fn stale(&self) -> bool {
+        matches!(self.source, KnownUnitSource::Imported(_))
+    }
+
+    /// When the unit was last visible, now for visible units.
+    #[inline]
+    pub fn last_seen(&self) -> SystemTime {
+        self.last_seen
+    }
+
+    /// The last tick the unit was visible at, the current tick for visible units. Remembered units
+    /// carry the tick of their [`crate::events::FlattiverseEventKind::UnitRemoved`], see
+    /// [`crate::events::FlattiverseEvent::tick`]. `None` if that happened before the first tick
+    /// after connecting. Ticks of imported units stem from the session which exported the map.
+    #[inline]
+    pub fn last_seen_tick(&self) -> Option<u32> {
+        self.last_seen_tick
+    }
+
+    /// The position the unit was last seen at.
+    #[inline]
+    pub fn last_seen_position(&self) -> Vector {
+        self.last_seen_position
+    }
+
+    /// Whether the unit is still where it was last seen or on its orbits, which holds for steady
+    /// units like suns, planets, moons, wormholes and gates. Mobile units may be anywhere by now.
+    /// Imported maps only contain steady units.
+    #[inline]
+    pub fn predictable(&self) -> bool {
+        match &self.source {
+            KnownUnitSource::Seen(unit) => unit.as_steady_unit().is_some(),
+            KnownUnitSource::Imported(_) => true,
+        }
+    }
+}
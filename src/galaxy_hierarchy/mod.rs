@@ -7,10 +7,55 @@ macro_rules! event {
     };
 }
 
+/// Implements [`std::fmt::Display`] for a subsystem: the [`SubsystemBase`] followed by the given
+/// values and, for the alternate form `{:#}`, the second list of values. Missing subsystems only
+/// show the base. Both lists are also captured as the [`SubsystemValue`]s of a [`GalaxySnapshot`].
+macro_rules! subsystem_fields {
+    ($ty:ty, [$($value:ident),* $(,)?] $(, [$($detail:ident),* $(,)?])?) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let base = crate::galaxy_hierarchy::AsSubsystemBase::as_subsystem_base(self);
+                std::fmt::Display::fmt(base, f)?;
+                if crate::galaxy_hierarchy::SubsystemExt::exists(base) {
+                    $(write!(f, concat!(" ", stringify!($value), "={}"), self.$value())?;)*
+                    $(if f.alternate() {
+                        $(write!(f, concat!(" ", stringify!($detail), "={}"), self.$detail())?;)*
+                    })?
+                }
+                Ok(())
+            }
+        }
+
+        impl crate::galaxy_hierarchy::SubsystemValues for $ty {
+            fn values(&self) -> Vec<crate::galaxy_hierarchy::SubsystemValue> {
+                vec![
+                    $(crate::galaxy_hierarchy::SubsystemValue {
+                        name: stringify!($value),
+                        value: self.$value().into(),
+                    },)*
+                    $($(crate::galaxy_hierarchy::SubsystemValue {
+                        name: stringify!($detail),
+                        value: self.$detail().into(),
+                    },)*)?
+                ]
+            }
+        }
+    };
+}
+
 mod galaxy;
 mod galaxy_tournament;
 pub use galaxy::*;
 
+mod galaxy_snapshot;
+pub use galaxy_snapshot::*;
+
+mod known_unit;
+pub use known_unit::*;
+
+mod motion_history;
+pub use motion_history::*;
+
 mod player;
 pub use player::*;
 
@@ -35,6 +80,9 @@ pub use editable_unit_summary::*;
 mod cluster;
 pub use cluster::*;
 
+mod cluster_map;
+pub use cluster_map::*;
+
 mod controllable_info;
 pub use controllable_info::*;
 
@@ -169,3 +169,17 @@ impl AsRef<SubsystemBase> for ModernRailgunSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    ModernRailgunSubsystem,
+    [direction],
+    [
+        projectile_speed,
+        projectile_lifetime,
+        energy_cost,
+        metal_cost,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -4,7 +4,7 @@ use crate::galaxy_hierarchy::{
     StaticInterceptorFabricatorSubsystem, StaticInterceptorLauncherSubsystem,
     StaticInterceptorMagazineSubsystem, StaticScannerSubsystem, StaticShotFabricatorSubsystem,
     StaticShotLauncherSubsystem, StaticShotMagazineSubsystem, SubsystemBase, SubsystemExt,
-    SystemExtIntern,
+    SubsystemState, SystemExtIntern,
 };
 use crate::network::PacketReader;
 use crate::utils::{Also, Readable};
@@ -126,6 +126,33 @@ impl ModernShipControllable {
             .chain([self.jump_drive.as_subsystem_base()])
     }
 
+    pub(crate) fn subsystem_states(&self) -> impl Iterator<Item = SubsystemState> + '_ {
+        [SubsystemState::capture(&self.nebula_collector)]
+            .into_iter()
+            .chain(self.engines.iter().map(SubsystemState::capture))
+            .chain(self.scanners.iter().map(SubsystemState::capture))
+            .chain(self.shot_launchers.iter().map(SubsystemState::capture))
+            .chain(self.shot_magazines.iter().map(SubsystemState::capture))
+            .chain(self.shot_fabricators.iter().map(SubsystemState::capture))
+            .chain(
+                self.interceptor_launchers
+                    .iter()
+                    .map(SubsystemState::capture),
+            )
+            .chain(
+                self.interceptor_magazines
+                    .iter()
+                    .map(SubsystemState::capture),
+            )
+            .chain(
+                self.interceptor_fabricators
+                    .iter()
+                    .map(SubsystemState::capture),
+            )
+            .chain(self.railguns.iter().map(SubsystemState::capture))
+            .chain([SubsystemState::capture(&self.jump_drive)])
+    }
+
     #[inline]
     pub fn nebula_collector(&self) -> &NebulaCollectorSubsystem {
         &self.nebula_collector
@@ -238,3 +238,17 @@ impl AsRef<SubsystemBase> for ModernShipEngineSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ModernShipEngineSubsystem,
+    [current_thrust, target_thrust],
+    [
+        maximum_thrust,
+        maximum_forward_thrust,
+        maximum_reverse_thrust,
+        maximum_thrust_change_per_tick,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
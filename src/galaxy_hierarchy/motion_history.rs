@@ -0,0 +1,224 @@
+use crate::unit::Unit;
+use crate::Vector;
+use std::collections::VecDeque;
+
+/// The position, movement and angle of a mobile unit at one tick, see [`MotionHistory`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionSample {
+    /// The tick of the update, the same as the one of its
+    /// [`crate::FlattiverseEventKind::UnitUpdated`].
+    pub tick: u32,
+    pub position: Vector,
+    pub movement: Vector,
+    pub angle: f32,
+}
+
+/// The last movement updates of a mobile unit, recorded if enabled by
+/// [`crate::galaxy_hierarchy::Galaxy::set_motion_history_length`].
+///
+/// The estimates are derived from the last two samples, so they react to a changed course within
+/// one update. Updates are usually received every tick, but gaps occur when the unit is at the edge
+/// of our scanners.
+#[derive(Debug, Clone, Default)]
+pub struct MotionHistory {
+    samples: VecDeque<MotionSample>,
+}
+
+impl MotionHistory {
+    /// Adds the current state of the unit and keeps the last `capacity` samples. A second update
+    /// within the same tick replaces the first one.
+    pub(crate) fn record(&mut self, unit: &dyn Unit, tick: u32, capacity: usize) {
+        let sample = MotionSample {
+            tick,
+            position: unit.position(),
+            movement: unit.movement(),
+            angle: unit.angle(),
+        };
+
+        if self.samples.back().is_some_and(|last| last.tick >= tick) {
+            self.samples.pop_back();
+        }
+        self.samples.push_back(sample);
+
+        while self.samples.len() > capacity.max(1) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// The recorded samples, oldest first.
+    #[inline]
+    pub fn samples(&self) -> impl Iterator<Item = &MotionSample> + '_ {
+        self.samples.iter()
+    }
+
+    #[inline]
+    pub fn latest(&self) -> Option<&MotionSample> {
+        self.samples.back()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The last two samples and the ticks between them, `None` with less than two samples.
+    fn last_two(&self) -> Option<(&MotionSample, &MotionSample, f32)> {
+        let mut samples = self.samples.iter().rev();
+        let last = samples.next()?;
+        let previous = samples.next()?;
+        Some((previous, last, last.tick.wrapping_sub(previous.tick) as f32))
+    }
+
+    /// The movement per tick derived from the recorded positions, independent of the movement
+    /// reported by the server.
+    pub fn estimated_movement(&self) -> Option<Vector> {
+        let (previous, last, ticks) = self.last_two()?;
+        Some((last.position - previous.position) / ticks)
+    }
+
+    /// The change of the movement per tick.
+    pub fn acceleration(&self) -> Option<Vector> {
+        let (previous, last, ticks) = self.last_two()?;
+        Some((last.movement - previous.movement) / ticks)
+    }
+
+    /// The change of the angle in degrees per tick, positive if the angle grows.
+    pub fn turning_rate(&self) -> Option<f32> {
+        let (previous, last, ticks) = self.last_two()?;
+        let mut degree = (last.angle - previous.angle) % 360.0;
+        if degree > 180.0 {
+            degree -= 360.0;
+        } else if degree <= -180.0 {
+            degree += 360.0;
+        }
+        Some(degree / ticks)
+    }
+
+    /// The average number of ticks between the recorded samples, `None` with less than two
+    /// samples.
+    pub fn average_update_gap(&self) -> Option<f32> {
+        let first = self.samples.front()?;
+        let last = self.samples.back()?;
+        (self.samples.len() > 1)
+            .then(|| last.tick.wrapping_sub(first.tick) as f32 / (self.samples.len() - 1) as f32)
+    }
+
+    /// Extrapolates the position `ticks_ahead` ticks after the latest sample, assuming the
+    /// [`MotionHistory::acceleration`] stays constant and is applied to the movement before each
+    /// tick. With a single sample the movement is assumed to be constant.
+    pub fn predicted_position(&self, ticks_ahead: u32) -> Option<MotionPrediction> {
+        let last = self.latest()?;
+        let ticks = ticks_ahead as f32;
+        let acceleration = self.acceleration().unwrap_or_default();
+
+        let movement = last.movement + acceleration * ticks;
+        let position =
+            last.position + last.movement * ticks + acceleration * (ticks * (ticks + 1.0) / 2.0);
+
+        // A gap of n ticks hides n - 1 ticks of the course, a single sample can't tell anything
+        // about the acceleration.
+        let confidence = match self.average_update_gap() {
+            Some(gap) => 1.0 / gap.max(1.0),
+            None => 0.5,
+        };
+
+        Some(MotionPrediction {
+            tick: last.tick.wrapping_add(ticks_ahead),
+            position,
+            movement,
+            confidence,
+        })
+    }
+}
+
+/// The result of [`MotionHistory::predicted_position`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionPrediction {
+    /// The galaxy tick the prediction is for.
+    pub tick: u32,
+    pub position: Vector,
+    pub movement: Vector,
+    /// Between 0 and 1. 1 if the unit was updated every tick, lower with gaps between the updates
+    /// or if there is only a single sample.
+    pub confidence: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(samples: &[(u32, Vector, f32)]) -> MotionHistory {
+        let mut positions = Vector::default();
+        MotionHistory {
+            samples: samples
+                .iter()
+                .map(|&(tick, movement, angle)| {
+                    positions += movement;
+                    MotionSample {
+                        tick,
+                        position: positions,
+                        movement,
+                        angle,
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn acceleration() {
+        let history = history(&[
+            (10, Vector::new(1.0, 0.0), 0.0),
+            (12, Vector::new(2.0, -1.0), 0.0),
+        ]);
+        assert_eq!(history.acceleration(), Some(Vector::new(0.5, -0.5)));
+        assert_eq!(history.estimated_movement(), Some(Vector::new(1.0, -0.5)));
+
+        assert_eq!(MotionHistory::default().acceleration(), None);
+    }
+
+    #[test]
+    fn turning_rate() {
+        let rate = |from: f32, to: f32, ticks: u32| {
+            history(&[
+                (1, Vector::default(), from),
+                (1 + ticks, Vector::default(), to),
+            ])
+            .turning_rate()
+            .unwrap()
+        };
+        assert_eq!(rate(10.0, 30.0, 1), 20.0);
+        assert_eq!(rate(30.0, 10.0, 2), -10.0);
+        // across 0°
+        assert_eq!(rate(350.0, 10.0, 1), 20.0);
+        assert_eq!(rate(10.0, 350.0, 1), -20.0);
+        // half a turn is reported as +180°, never as -180°
+        assert_eq!(rate(0.0, 180.0, 1), 180.0);
+        assert_eq!(rate(180.0, 0.0, 1), 180.0);
+    }
+
+    #[test]
+    fn confidence() {
+        let confidence = |ticks: &[u32]| {
+            history(
+                &ticks
+                    .iter()
+                    .map(|&tick| (tick, Vector::new(1.0, 0.0), 0.0))
+                    .collect::<Vec<_>>(),
+            )
+            .predicted_position(1)
+            .unwrap()
+            .confidence
+        };
+        assert_eq!(confidence(&[5]), 0.5);
+        assert_eq!(confidence(&[5, 6, 7]), 1.0);
+        assert_eq!(confidence(&[5, 7, 9]), 0.5);
+        assert_eq!(confidence(&[5, 9]), 0.25);
+        assert!(MotionHistory::default().predicted_position(1).is_none());
+    }
+}
@@ -237,3 +237,16 @@ impl AsRef<SubsystemBase> for NebulaCollectorSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    NebulaCollectorSubsystem,
+    [rate, collected_this_tick],
+    [
+        minimum_rate,
+        maximum_rate,
+        collected_hue_this_tick,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -4,9 +4,12 @@ use crate::galaxy_hierarchy::{
 };
 use crate::utils::Atomic;
 use crate::{GameError, GameErrorKind, ProgressState};
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(
+    Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, serde::Serialize, serde::Deserialize,
+)]
 pub struct PlayerId(pub(crate) u8);
 
 impl Indexer for PlayerId {
@@ -348,6 +351,38 @@ impl Player {
     }
 }
 
+impl Display for Player {
+    /// `"name" (Kind) team= rank= ping=`, the alternate form adds the kills and deaths.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ({})", self.name, self.kind)?;
+        if let Some(team) = self.team.upgrade() {
+            write!(f, " team={:?}", &*team.name())?;
+        }
+        write!(f, " rank={} ping={:.1}ms", self.rank(), self.ping())?;
+        if self.admin() {
+            f.write_str(" admin")?;
+        }
+        if self.disconnected() {
+            f.write_str(" disconnected")?;
+        }
+        if f.alternate() {
+            write!(
+                f,
+                " player_kills={} player_deaths={} friendly_kills={} friendly_deaths={} \
+                 npc_kills={} npc_deaths={} neutral_deaths={}",
+                self.player_kills(),
+                self.player_deaths(),
+                self.friendly_kills(),
+                self.friendly_deaths(),
+                self.npc_kills(),
+                self.npc_deaths(),
+                self.neutral_deaths(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl Identifiable<PlayerId> for Player {
     #[inline]
     fn id(&self) -> PlayerId {
@@ -367,6 +402,7 @@ impl Identifiable<PlayerId> for Player {
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
 )]
 pub enum PlayerKind {
     /// It's a regular player which can register ships, etc.
@@ -14,6 +14,9 @@ use num_enum::FromPrimitive;
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum RailgunDirection {
     /// No direction was processed in the current tick.
@@ -234,3 +234,15 @@ impl AsRef<SubsystemBase> for RepairSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    RepairSubsystem,
+    [rate, repaired_hull_this_tick],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -259,3 +259,19 @@ impl AsRef<SubsystemBase> for ResourceMinerSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ResourceMinerSubsystem,
+    [rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        mined_metal_this_tick,
+        mined_carbon_this_tick,
+        mined_hydrogen_this_tick,
+        mined_silicon_this_tick,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
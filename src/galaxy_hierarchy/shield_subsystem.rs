@@ -313,3 +313,15 @@ impl AsRef<SubsystemBase> for ShieldSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(
+    ShieldSubsystem,
+    [current, maximum, active, rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -178,3 +178,15 @@ impl AsRef<SubsystemBase> for StaticInterceptorFabricatorSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    StaticInterceptorFabricatorSubsystem,
+    [active, rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -62,3 +62,8 @@ impl AsRef<SubsystemBase> for StaticInterceptorMagazineSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    StaticInterceptorMagazineSubsystem,
+    [current_shots, maximum_shots]
+);
@@ -293,3 +293,23 @@ impl AsRef<SubsystemBase> for StaticScannerSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    StaticScannerSubsystem,
+    [active, current_width, current_length, current_angle],
+    [
+        target_width,
+        target_length,
+        target_angle,
+        minimum_width,
+        maximum_width,
+        minimum_length,
+        maximum_length,
+        width_speed,
+        length_speed,
+        angle_speed,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
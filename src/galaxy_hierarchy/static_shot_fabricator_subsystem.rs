@@ -176,3 +176,15 @@ impl AsRef<SubsystemBase> for StaticShotFabricatorSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    StaticShotFabricatorSubsystem,
+    [active, rate],
+    [
+        minimum_rate,
+        maximum_rate,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick
+    ]
+);
@@ -276,3 +276,22 @@ impl AsRef<SubsystemBase> for StaticShotLauncherSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(
+    StaticShotLauncherSubsystem,
+    [relative_movement, ticks, load, damage],
+    [
+        minimum_relative_movement,
+        maximum_relative_movement,
+        minimum_ticks,
+        maximum_ticks,
+        minimum_load,
+        maximum_load,
+        minimum_damage,
+        maximum_damage,
+        consumed_energy_this_tick,
+        consumed_ions_this_tick,
+        consumed_neutrinos_this_tick,
+        relative_speed
+    ]
+);
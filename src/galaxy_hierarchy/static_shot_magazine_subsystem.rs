@@ -58,3 +58,5 @@ impl AsRef<SubsystemBase> for StaticShotMagazineSubsystem {
         self.base.as_ref()
     }
 }
+
+subsystem_fields!(StaticShotMagazineSubsystem, [current_shots, maximum_shots]);
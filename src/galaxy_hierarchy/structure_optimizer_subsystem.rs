@@ -52,3 +52,5 @@ impl AsRef<SubsystemBase> for StructureOptimizerSubsystem {
         &self.base
     }
 }
+
+subsystem_fields!(StructureOptimizerSubsystem, [reduction_percentage]);
@@ -5,6 +5,7 @@ use crate::unit::UnitKind;
 use crate::utils::Atomic;
 use crate::{SubsystemSlot, SubsystemStatus};
 use arc_swap::ArcSwapWeak;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// Base type for persistent controllable subsystems.
@@ -96,6 +97,20 @@ impl SubsystemBase {
     }
 }
 
+impl Display for SubsystemBase {
+    /// `name t{tier} status`, the alternate form adds the slot.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.exists() {
+            return write!(f, "{} missing", self.name);
+        }
+        write!(f, "{} t{} {}", self.name, self.tier(), self.status())?;
+        if f.alternate() {
+            write!(f, " slot={:?}", self.slot)?;
+        }
+        Ok(())
+    }
+}
+
 pub trait AsSubsystemBase {
     fn as_subsystem_base(&self) -> &SubsystemBase;
 }
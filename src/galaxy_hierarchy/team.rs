@@ -3,10 +3,13 @@ use crate::utils::Atomic;
 use crate::utils::GuardedArcStringDeref;
 use crate::GameError;
 use arc_swap::ArcSwap;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::sync::{Arc, Weak};
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(
+    Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, serde::Serialize, serde::Deserialize,
+)]
 pub struct TeamId(pub(crate) u8);
 
 impl Indexer for TeamId {
@@ -130,6 +133,24 @@ impl Team {
     }
 }
 
+impl Display for Team {
+    /// `"name" #id`, the alternate form adds the color and whether the team is playable.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} #{}", &*self.name(), self.id.0)?;
+        if f.alternate() {
+            write!(
+                f,
+                " rgb=({}, {}, {}) playable={}",
+                self.red(),
+                self.green(),
+                self.blue(),
+                self.playable()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl Identifiable<TeamId> for Team {
     #[inline]
     fn id(&self) -> TeamId {
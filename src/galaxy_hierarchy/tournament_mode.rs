@@ -10,6 +10,7 @@
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    serde::Serialize,
 )]
 pub enum TournamentMode {
     /// One single decisive match.
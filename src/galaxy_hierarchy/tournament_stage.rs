@@ -10,6 +10,7 @@
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    serde::Serialize,
 )]
 pub enum TournamentStage {
     /// Tournament exists but has not been commenced yet.
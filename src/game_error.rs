@@ -193,6 +193,9 @@ pub enum GameErrorKind {
         r#type: &'static str,
     },
     PacketNotCompletelyRead(usize),
+    /// The event queue could not keep up and has been closed, see
+    /// [`crate::OverflowPolicy::Disconnect`].
+    EventQueueOverflowed,
 }
 
 impl Display for GameErrorKind {
@@ -281,6 +284,7 @@ impl Display for GameErrorKind {
             GameErrorKind::DuplicateSubsystemComponentValue {component_kind} => return write!(f, "[0x40] The subsystem component \"{component_kind:?}\" was supplied more than once."),
             GameErrorKind::InvalidPrimitiveValue { value, r#type } => return write!(f, "[0x??] Value {value:?} not expected for  {type:?}"),
             GameErrorKind::PacketNotCompletelyRead(bytes) => return write!(f, "[0x??] The packet has unread bytes remaining: {bytes}"),
+            GameErrorKind::EventQueueOverflowed => "[0x??] The event queue overflowed and has been disconnected.",
         })
     }
 }
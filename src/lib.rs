@@ -8,6 +8,7 @@ pub use async_channel;
 pub use tokio;
 
 pub mod account;
+pub mod chat;
 pub mod galaxy_hierarchy;
 pub mod network;
 pub mod runtime;
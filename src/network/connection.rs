@@ -1,29 +1,55 @@
 use crate::galaxy_hierarchy::{
-    ClusterId, ControllableId, ControllableInfoId, Galaxy, GameMode, PlayerId, PlayerKind, TeamId,
+    ClusterId, ControllableId, ControllableInfoId, EventSink, Galaxy, GameMode, PlayerId,
+    PlayerKind, TeamId,
 };
 use crate::game_error::GameError;
 use crate::network::{ConnectionHandle, Packet, SessionId};
 use crate::unit::UnitKind;
 use crate::utils::Readable;
 use crate::{FlattiverseEvent, FlattiverseEventKind, GameErrorKind, PlayerUnitDestroyedReason};
-use async_channel::Sender;
 use num_enum::FromPrimitive;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 
 pub struct Connection {
     pub(crate) handle: ConnectionHandle,
     pub(crate) galaxy: Weak<Galaxy>,
-    pub(crate) sender: Sender<FlattiverseEvent>,
+    pub(crate) terminated: AtomicBool,
+}
+
+impl Drop for Connection {
+    #[inline]
+    fn drop(&mut self) {
+        // the event queues must not outlive the connection, even if it ended without a close
+        // message
+        self.on_close(Some(Arc::from("Connection driver stopped")));
+    }
 }
 
 impl Connection {
+    /// Raises the final [`FlattiverseEventKind::ConnectionTerminated`] and closes all event queues
+    /// and sessions. Only the first call has an effect.
     #[inline]
     pub(crate) fn on_close(&self, reason: Option<Arc<str>>) {
+        if self.terminated.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
         if let Some(reason) = &reason {
             warn!("Closing connection: {reason}");
         }
-        self.sender.close();
+
+        let mut event = FlattiverseEvent::from(FlattiverseEventKind::ConnectionTerminated {
+            message: reason.as_deref().map(String::from),
+        });
+        self.handle.stamp_tick(&mut event);
+        self.handle
+            .history
+            .record(&event, || self.handle.subscribers.terminate(&event));
+        self.handle.events.push_unbounded(event);
+
+        self.handle.events.close();
         self.handle.sessions.close_all(reason);
     }
 
@@ -35,17 +61,51 @@ impl Connection {
         allow(unused)
     )] // TODO JS-WebSockets do not support Ping/Pong atm
     pub(crate) fn on_ping_measured(&self, duration: Duration) -> Result<(), GameError> {
-        self.sender
-            .try_send(FlattiverseEventKind::PingMeasured(duration).into())
-            .map_err(|_| {
-                GameError::from(GameErrorKind::ConnectionTerminated {
-                    reason: Some(Arc::from("Failed to send ping")),
-                })
-            })
+        let event = FlattiverseEvent::from(FlattiverseEventKind::PingMeasured(duration));
+        if self.handle.dispatch_event(event) {
+            Ok(())
+        } else {
+            Err(self
+                .handle
+                .events
+                .state()
+                .closed_error("Failed to send ping"))
+        }
+    }
+
+    /// Whether packets may be applied to the mirror, which is not the case while a
+    /// [`crate::TickFrame`] holds the connection or an event queue with
+    /// [`crate::OverflowPolicy::Block`] is full. Replies to commands are never held back.
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) fn accepts_packets(&self) -> bool {
+        self.handle.tick_gate.is_open()
+            && self.handle.events.has_space()
+            && self.handle.subscribers.has_space()
+    }
+
+    /// Waits until [`Connection::accepts_packets`].
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) async fn packets_accepted(&self) {
+        self.handle.tick_gate.opened().await;
+        self.handle.events.wait_for_space().await;
+        self.handle.subscribers.wait_for_space().await;
     }
 
-    pub(crate) fn handle(&self, packet: Packet) -> Result<(), GameError> {
-        let mut events = Vec::with_capacity(2);
+    /// Processes one packet. `events` is only used as scratch space and is empty again when this
+    /// returns, so callers can reuse it for the next packet instead of allocating a new one.
+    pub(crate) fn handle(&self, packet: Packet, events: &mut EventSink) -> Result<(), GameError> {
         if let Some(galaxy) = self.galaxy.upgrade() {
             if packet.header().session() != 0 {
                 self.handle
@@ -53,20 +113,22 @@ impl Connection {
                     .resolve(SessionId(packet.header().session()), packet);
                 Ok(())
             } else {
-                match self.on_packet(packet, &galaxy, &mut events) {
+                match self.on_packet(packet, &galaxy, events) {
                     Ok(()) => {
                         for event in events.drain(..) {
-                            if self.sender.try_send(event).is_err() {
+                            if !self.handle.dispatch_event(event) {
                                 error!("Event-Receiver gone, shutting down connection!");
-                                return Err(GameErrorKind::ConnectionTerminated {
-                                    reason: Some(Arc::from("Event-Receiver gone")),
-                                }
-                                .into());
+                                return Err(self
+                                    .handle
+                                    .events
+                                    .state()
+                                    .closed_error("Event-Receiver gone"));
                             }
                         }
                         Ok(())
                     }
                     Err(e) => {
+                        events.clear();
                         error!("Failed to process packet: {e:?}");
                         Err(e)
                     }
@@ -86,7 +148,7 @@ impl Connection {
         &self,
         mut packet: Packet,
         galaxy: &Arc<Galaxy>,
-        events: &mut Vec<FlattiverseEvent>,
+        events: &mut EventSink,
     ) -> Result<(), GameError> {
         let command = packet.header().command();
         packet.read(|reader| match command {
@@ -0,0 +1,70 @@
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[cfg(all(
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+))]
+type DriverFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+#[cfg(not(all(
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+)))]
+type DriverFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// The background work of one galaxy connection: it reads frames from the websocket, feeds them
+/// into the [`crate::galaxy_hierarchy::Galaxy`] mirror and writes outgoing packets and pings.
+///
+/// The connector itself never decides where this future runs. It is handed to the executor
+/// passed to [`crate::galaxy_hierarchy::Galaxy::connect_to_with_executor`], which may spawn it on
+/// any runtime or poll it manually. The future completes once the connection is closed.
+///
+/// On desktop targets the websocket is still a tokio socket, so the future has to be polled
+/// within a tokio context with IO and time enabled. A `current_thread` runtime is sufficient.
+#[must_use = "the connection makes no progress unless the driver is polled"]
+pub struct ConnectionDriver(DriverFuture);
+
+impl ConnectionDriver {
+    #[cfg(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    ))]
+    #[inline]
+    pub(crate) fn new(future: impl Future<Output = ()> + 'static) -> Self {
+        Self(Box::pin(future))
+    }
+
+    #[cfg(not(all(
+        any(target_arch = "wasm32", target_arch = "wasm64"),
+        target_os = "unknown"
+    )))]
+    #[inline]
+    pub(crate) fn new(future: impl Future<Output = ()> + Send + 'static) -> Self {
+        Self(Box::pin(future))
+    }
+
+    /// Spawns this driver onto the current default runtime, see [`crate::runtime::spawn`].
+    #[inline]
+    pub fn spawn(self) {
+        crate::runtime::spawn(self);
+    }
+}
+
+impl Future for ConnectionDriver {
+    type Output = ();
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+impl Debug for ConnectionDriver {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionDriver").finish_non_exhaustive()
+    }
+}
@@ -5,12 +5,14 @@ use crate::galaxy_hierarchy::{
 };
 use crate::network::{
     ChunkedTransfer, InvalidArgumentKind, Packet, PacketReader, PacketWriter, Session,
-    SessionHandler,
+    SessionHandler, TickGate,
 };
 use crate::unit::UnitKind;
-use crate::utils::{check_name_or_err, Readable};
-use crate::{FlattiverseEvent, GameError, GameErrorKind, ProgressState, SubsystemSlot, Vector};
-use async_channel::WeakSender;
+use crate::utils::{check_name_or_err, Atomic, Readable};
+use crate::{
+    EventHistory, EventQueue, EventSubscribers, FlattiverseEvent, FlattiverseEventKind, GameError,
+    GameErrorKind, ProgressState, SubsystemSlot, Vector,
+};
 use serde::Serialize;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
@@ -23,7 +25,12 @@ use tokio::sync::oneshot::error::RecvError;
 pub struct ConnectionHandle {
     pub(crate) sender: Sender<SenderData>,
     pub(crate) sessions: Arc<SessionHandler>,
-    pub(crate) event_sender: WeakSender<FlattiverseEvent>,
+    pub(crate) events: Arc<EventQueue>,
+    pub(crate) subscribers: Arc<EventSubscribers>,
+    pub(crate) history: Arc<EventHistory>,
+    pub(crate) tick_gate: Arc<TickGate>,
+    /// The number of the last [`crate::FlattiverseEventKind::GalaxyTick`] received.
+    pub(crate) tick: Arc<Atomic<Option<u32>>>,
 }
 
 impl Debug for ConnectionHandle {
@@ -34,17 +41,43 @@ impl Debug for ConnectionHandle {
 }
 
 impl ConnectionHandle {
-    pub(crate) fn new(
-        sender: Sender<SenderData>,
-        event_sender: WeakSender<FlattiverseEvent>,
-    ) -> Self {
+    pub(crate) fn new(sender: Sender<SenderData>, events: EventQueue) -> Self {
         Self {
             sender,
             sessions: Arc::default(),
-            event_sender,
+            events: Arc::new(events),
+            subscribers: Arc::default(),
+            history: Arc::default(),
+            tick_gate: Arc::default(),
+            tick: Arc::default(),
         }
     }
 
+    /// Hands the event to the history, the subscriptions and the queue of
+    /// [`Galaxy::next_event`]. Returns `false` if that queue is closed.
+    pub(crate) fn dispatch_event(&self, mut event: FlattiverseEvent) -> bool {
+        self.stamp_tick(&mut event);
+        self.history
+            .record(&event, || self.subscribers.publish(&event));
+        self.events.push(event)
+    }
+
+    /// Records the tick the event belongs to, see [`FlattiverseEvent::tick`].
+    pub(crate) fn stamp_tick(&self, event: &mut FlattiverseEvent) {
+        let tick = match event.kind() {
+            FlattiverseEventKind::GalaxyTick { tick, .. } => Some(*tick),
+            _ => self.event_tick(),
+        };
+        event.stamp_tick(tick);
+    }
+
+    /// The tick the events raised now belong to: the one after the last received tick, `None`
+    /// before the first one.
+    #[inline]
+    pub(crate) fn event_tick(&self) -> Option<u32> {
+        self.tick.load().map(|tick| tick.wrapping_add(1))
+    }
+
     /// Sends a chat message to the connected [`crate::galaxy_hierarchy::Player`].
     #[inline]
     pub async fn chat_player(
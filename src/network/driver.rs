@@ -1,12 +1,13 @@
-use crate::galaxy_hierarchy::Galaxy;
+use crate::galaxy_hierarchy::{EventSink, Galaxy};
 use crate::network::connection_handle::ConnectionHandle;
-use crate::network::packet::MultiPacketBuffer;
-use crate::network::{ConnectError, Connection, SenderData};
-use crate::FlattiverseEvent;
-use bytes::BytesMut;
+use crate::network::packet::{MultiPacketBuffer, Packet};
+use crate::network::{ConnectError, Connection, ConnectionDriver, SenderData};
+use crate::{EventQueue, EventQueueOptions, FlattiverseEvent};
 use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
+use std::collections::VecDeque;
 use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::{Duration, UNIX_EPOCH};
 use tokio::net::TcpStream;
@@ -24,6 +25,7 @@ pub const ENV_PROXY: &str = "http_proxy";
 pub async fn connect(
     url: &str,
     f: impl FnOnce(ConnectionHandle, async_channel::Receiver<FlattiverseEvent>) -> Arc<Galaxy>,
+    executor: impl FnOnce(ConnectionDriver),
 ) -> Result<Arc<Galaxy>, ConnectError> {
     let url = Url::from_str(url).map_err(ConnectError::MalformedHostUrl)?;
     let (mut stream, _response) = match std::env::var(ENV_PROXY).ok() {
@@ -61,37 +63,38 @@ pub async fn connect(
 
     let (sink, stream) = stream.split();
     let (data_sender, data_receiver) = tokio::sync::mpsc::channel(1024);
-    let (event_sender, event_receiver) = async_channel::unbounded();
+    let (events, event_receiver) = EventQueue::new(EventQueueOptions::unbounded());
 
-    let handle = ConnectionHandle::new(data_sender.clone(), event_sender.downgrade());
+    let handle = ConnectionHandle::new(data_sender.clone(), events);
     let galaxy = f(handle.clone(), event_receiver);
     let connection = Connection {
         handle,
         galaxy: Arc::downgrade(&galaxy),
-        sender: event_sender,
+        terminated: AtomicBool::default(),
     };
 
-    let mut sender_handle =
-        tokio::spawn(ConnectionSender { sink }.run(data_receiver, PING_INTERVAL));
-    let receiver_handle = ConnectionReceiver { stream, connection }.run(data_sender);
+    let sender = ConnectionSender { sink }.run(data_receiver, PING_INTERVAL);
+    let receiver = ConnectionReceiver {
+        stream,
+        connection,
+        events: Vec::new(),
+    }
+    .run(data_sender);
 
-    tokio::spawn({
-        async move {
-            tokio::select! {
-                r = &mut sender_handle => {
-                    if let Err(e) = r {
-                        eprintln!("ConnectionSender failed: {e:?}");
-                    }
-                },
-                r = receiver_handle => {
-                    sender_handle.abort();
-                    if let Err(e) = r {
-                        eprintln!("ConnectionReceiver failed: {e:?}")
-                    }
+    executor(ConnectionDriver::new(async move {
+        tokio::select! {
+            r = sender => {
+                if let Err(e) = r {
+                    eprintln!("ConnectionSender failed: {e:?}");
+                }
+            },
+            r = receiver => {
+                if let Err(e) = r {
+                    eprintln!("ConnectionReceiver failed: {e:?}")
                 }
             }
         }
-    });
+    }));
 
     Ok(galaxy)
 }
@@ -139,7 +142,7 @@ impl ConnectionSender {
                             self.send(message).await?;
                         }
                         Some(SenderData::Packet(packet)) => {
-                            self.send(Message::Binary(packet.into_buf().to_vec())).await?;
+                            self.send(Message::Binary(Vec::from(packet.into_buf()))).await?;
                         }
                         None => return Ok(())
                     }
@@ -176,22 +179,62 @@ pub enum SenderError {
 struct ConnectionReceiver {
     stream: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
     connection: Connection,
+    events: EventSink,
 }
 
 impl ConnectionReceiver {
     async fn run(mut self, sender: Sender<SenderData>) -> Result<(), ReceiveError> {
-        while let Some(message) = self.stream.next().await.transpose()? {
+        let result = self.receive(&sender).await;
+        self.connection.on_close(match &result {
+            Ok(()) => None,
+            Err(e) => Some(Arc::from(e.to_string())),
+        });
+        result
+    }
+
+    async fn receive(&mut self, sender: &Sender<SenderData>) -> Result<(), ReceiveError> {
+        // packets received while the connection does not accept them, applied in order later
+        let mut deferred = VecDeque::new();
+
+        loop {
+            while self.connection.accepts_packets() {
+                match deferred.pop_front() {
+                    Some(packet) => self.apply(packet)?,
+                    None => break,
+                }
+            }
+
+            let message = if deferred.is_empty() {
+                self.stream.next().await
+            } else {
+                tokio::select! {
+                    message = self.stream.next() => message,
+                    _ = self.connection.packets_accepted() => continue,
+                }
+            };
+
+            let Some(message) = message.transpose()? else {
+                return Ok(());
+            };
+
             match message {
                 b @ (Message::Frame(_) | Message::Text(_)) => {
                     return Err(ReceiveError::UnexpectedData(format!("{b:?}")));
                 }
                 Message::Binary(bin) => {
-                    // TODO sad copy
-                    let mut packet = MultiPacketBuffer::from(BytesMut::from(&bin[..]));
+                    let mut packet = MultiPacketBuffer::from(bin);
                     while let Some(packet) = packet.next_packet() {
-                        if let Err(e) = self.connection.handle(packet) {
-                            error!("Failed to handle Packet: {e:?}");
-                            return Err(ReceiveError::GalaxyGone);
+                        if packet.header().session() != 0 {
+                            // the reply may refer to anything the server sent before it, like a
+                            // new controllable, so it must not overtake the deferred packets
+                            while let Some(packet) = deferred.pop_front() {
+                                self.apply(packet)?;
+                            }
+                            self.apply(packet)?;
+                        } else if deferred.is_empty() && self.connection.accepts_packets() {
+                            self.apply(packet)?;
+                        } else {
+                            deferred.push_back(packet);
                         }
                     }
                 }
@@ -223,6 +266,9 @@ impl ConnectionReceiver {
                         msg.as_ref().map(|m| m.code),
                         msg.as_ref().map(|m| m.reason.as_ref()).unwrap_or_default()
                     );
+                    while let Some(packet) = deferred.pop_front() {
+                        self.apply(packet)?;
+                    }
                     let _ = sender.try_send(SenderData::Close);
                     self.connection
                         .on_close(msg.map(|m| Arc::from(m.reason.as_ref())));
@@ -230,7 +276,16 @@ impl ConnectionReceiver {
                 }
             }
         }
-        Ok(())
+    }
+
+    #[inline]
+    fn apply(&mut self, packet: Packet) -> Result<(), crate::GameError> {
+        self.connection
+            .handle(packet, &mut self.events)
+            .map_err(|e| {
+                error!("Failed to handle Packet: {e:?}");
+                e
+            })
     }
 }
 
@@ -242,6 +297,192 @@ pub enum ReceiveError {
     ConnectionError(#[from] tokio_tungstenite::tungstenite::Error),
     #[error("The Galaxy is no longer reachable")]
     GalaxyGone,
+    #[error("Failed to handle packet: {0}")]
+    Handling(#[from] crate::GameError),
     #[error("Unexpected data received: {0}")]
     UnexpectedData(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::galaxy_hierarchy::Galaxy;
+    use crate::network::{Packet, PacketWriter};
+    use crate::{EventQueueOptions, FlattiverseEventKind, OverflowPolicy};
+    use futures_util::{SinkExt, StreamExt};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::WebSocketStream;
+
+    type Server = WebSocketStream<tokio::net::TcpStream>;
+
+    fn packet(command: u8, session: u8, f: impl FnOnce(&mut dyn PacketWriter)) -> Vec<u8> {
+        let mut packet = Packet::default();
+        packet.header_mut().set_command(command);
+        packet.header_mut().set_session(session);
+        packet.write(f);
+        packet.into_buf().to_vec()
+    }
+
+    fn tick(number: u32) -> Message {
+        Message::Binary(packet(0xC0, 0, |writer| {
+            writer.write_uint32(number);
+            for _ in 0..10 {
+                writer.write_f32(0.0);
+            }
+            writer.write_int32(0);
+        }))
+    }
+
+    fn reply(session: u8) -> Message {
+        Message::Binary(packet(0x00, session, |_| {}))
+    }
+
+    /// Accepts the connection and logs the client in as a spectator.
+    async fn login(listener: TcpListener) -> Server {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut server = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        let mut welcome = packet(0x10, 0, |writer| {
+            writer.write_byte(0);
+            writer.write_byte(0x02);
+            writer.write_byte(Galaxy::SPECTATORS_TEAM_ID.0);
+            writer.write_string_with_len_prefix("bot");
+            writer.write_f32(0.0);
+            writer.write_boolean(false);
+            writer.write_byte(0);
+            writer.write_int32(0);
+            for _ in 0..7 {
+                writer.write_int64(0);
+            }
+            writer.write_boolean(false);
+            writer.write_byte(0);
+        });
+        welcome.extend(packet(0x00, 1, |writer| writer.write_byte(0)));
+        server.send(Message::Binary(welcome)).await.unwrap();
+        server
+    }
+
+    /// The session of the next command sent by the client.
+    async fn next_command(server: &mut Server) -> u8 {
+        loop {
+            match server.next().await {
+                Some(Ok(Message::Binary(request))) => return request[1],
+                Some(Ok(_)) => continue,
+                other => panic!("Connection lost: {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn command_inside_tick_frame() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut server = login(listener).await;
+            let session = next_command(&mut server).await;
+            server.send(reply(session)).await.unwrap();
+            server.send(tick(1)).await.unwrap();
+            let session = next_command(&mut server).await;
+            server.send(tick(2)).await.unwrap();
+            server.send(reply(session)).await.unwrap();
+            server
+        });
+
+        let galaxy = Galaxy::connect_to(&uri, None, None, None, None)
+            .await
+            .unwrap();
+
+        // enter the lockstep before the server starts ticking
+        let frame = galaxy.next_tick_frame();
+        tokio::pin!(frame);
+        assert!(futures_util::poll!(frame.as_mut()).is_pending());
+        galaxy.chat("start").await.unwrap();
+
+        let frame = frame.await.unwrap();
+        assert_eq!(frame.tick(), 1);
+
+        tokio::time::timeout(Duration::from_secs(5), galaxy.chat("hello"))
+            .await
+            .expect("the reply was held back by the tick frame")
+            .unwrap();
+
+        drop(frame);
+        assert_eq!(galaxy.next_tick_frame().await.unwrap().tick(), 2);
+        drop(server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn ticks_queued_before_the_lockstep_are_merged() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut server = login(listener).await;
+            for number in 1..=3 {
+                server.send(tick(number)).await.unwrap();
+            }
+            for _ in 0..2 {
+                let session = next_command(&mut server).await;
+                server.send(reply(session)).await.unwrap();
+            }
+            server.send(tick(4)).await.unwrap();
+            server.send(tick(5)).await.unwrap();
+            server
+        });
+
+        let galaxy = Galaxy::connect_to(&uri, None, None, None, None)
+            .await
+            .unwrap();
+        // the reply is sent after the first ticks, so they have been applied once it arrives
+        galaxy.chat("hello").await.unwrap();
+        assert_eq!(galaxy.tick(), Some(3));
+
+        let frame = galaxy.next_tick_frame();
+        tokio::pin!(frame);
+        assert!(futures_util::poll!(frame.as_mut()).is_pending());
+        galaxy.chat("start").await.unwrap();
+
+        let frame = frame.await.unwrap();
+        assert_eq!(frame.tick(), 4);
+        assert_eq!(galaxy.tick(), Some(4));
+        assert_eq!(
+            frame
+                .iter()
+                .filter(|event| matches!(event.kind(), FlattiverseEventKind::GalaxyTick { .. }))
+                .count(),
+            3
+        );
+
+        drop(frame);
+        assert_eq!(galaxy.next_tick_frame().await.unwrap().tick(), 5);
+        drop(server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn command_while_event_queue_blocks() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut server = login(listener).await;
+            let session = next_command(&mut server).await;
+            for number in 1..=8 {
+                server.send(tick(number)).await.unwrap();
+            }
+            server.send(reply(session)).await.unwrap();
+            server
+        });
+
+        let galaxy = Galaxy::connect_to(&uri, None, None, None, None)
+            .await
+            .unwrap();
+        galaxy.set_event_queue_options(
+            EventQueueOptions::bounded(1).with_overflow_policy(OverflowPolicy::Block),
+        );
+
+        tokio::time::timeout(Duration::from_secs(5), galaxy.chat("hello"))
+            .await
+            .expect("the reply was held back by the full event queue")
+            .unwrap();
+        drop(server.await.unwrap());
+    }
+}
@@ -1,9 +1,10 @@
 use crate::galaxy_hierarchy::Galaxy;
 use crate::network::packet::MultiPacketBuffer;
-use crate::network::{ConnectError, Connection, ConnectionHandle, SenderData};
-use crate::FlattiverseEvent;
+use crate::network::{ConnectError, Connection, ConnectionDriver, ConnectionHandle, SenderData};
+use crate::{EventQueue, EventQueueOptions, FlattiverseEvent};
 use async_channel::Receiver;
 use bytes::BytesMut;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use web_sys::js_sys::{ArrayBuffer, JsString, Uint8Array};
 use web_sys::wasm_bindgen::closure::Closure;
@@ -13,6 +14,7 @@ use web_sys::{Blob, CloseEvent, MessageEvent, WebSocket};
 pub async fn connect(
     url: &str,
     f: impl FnOnce(ConnectionHandle, Receiver<FlattiverseEvent>) -> Arc<Galaxy>,
+    executor: impl FnOnce(ConnectionDriver),
 ) -> Result<Arc<Galaxy>, ConnectError> {
     debug!("Connecting to {url:?}");
     match WebSocket::new(&url) {
@@ -20,20 +22,22 @@ pub async fn connect(
             debug!("Target URL seems fine");
             websocket.set_binary_type(web_sys::BinaryType::Arraybuffer);
             let (data_sender, mut data_receiver) = tokio::sync::mpsc::channel(124);
-            let (event_sender, event_receiver) = async_channel::unbounded();
+            let (events, event_receiver) = EventQueue::new(EventQueueOptions::unbounded());
 
-            let handle = ConnectionHandle::new(data_sender.clone(), event_sender.downgrade());
+            let handle = ConnectionHandle::new(data_sender.clone(), events);
             let galaxy = f(handle.clone(), event_receiver);
             let connection = Arc::new(Connection {
                 handle,
                 galaxy: Arc::downgrade(&galaxy),
-                sender: event_sender.clone(),
+                terminated: AtomicBool::default(),
             });
 
             let on_message_callback = Closure::<dyn FnMut(_)>::new({
                 let connection = connection.clone();
                 let data_sender = data_sender.clone();
                 let websocket = websocket.clone();
+                let mut buffer = BytesMut::new();
+                let mut events = Vec::new();
                 move |msg: MessageEvent| {
                     let array = if let Ok(buffer) = msg.data().dyn_into::<ArrayBuffer>() {
                         Uint8Array::new(&buffer)
@@ -49,15 +53,18 @@ pub async fn connect(
 
                     debug!("received msg, len={}", array.byte_length());
                     let data = {
-                        // copying the data from into rust / wasm
-                        let mut bytes = BytesMut::zeroed(array.byte_length() as usize);
-                        array.copy_to(&mut bytes[..]);
-                        bytes
+                        // copying the data from js into rust / wasm is unavoidable, but reserve()
+                        // reclaims the allocation once all packets of the last message are dropped
+                        let len = array.byte_length() as usize;
+                        buffer.reserve(len);
+                        buffer.resize(len, 0);
+                        array.copy_to(&mut buffer[..]);
+                        buffer.split()
                     };
 
                     let mut packet = MultiPacketBuffer::from(data);
                     while let Some(packet) = packet.next_packet() {
-                        if let Err(e) = connection.handle(packet) {
+                        if let Err(e) = connection.handle(packet, &mut events) {
                             error!("Failed to send ConnectionEvent {e:?}");
                             let _ = data_sender.try_send(SenderData::Close);
                             let _ = websocket.close();
@@ -86,7 +93,7 @@ pub async fn connect(
             websocket.set_onclose(Some(on_close_callback.as_ref().unchecked_ref()));
             on_close_callback.forget();
 
-            wasm_bindgen_futures::spawn_local(async move {
+            executor(ConnectionDriver::new(async move {
                 debug!("FUTURE SPAWNED");
 
                 loop {
@@ -114,7 +121,7 @@ pub async fn connect(
 
                 warn!("SENDER IS SHUTTING DOWN");
                 let _ = websocket.close();
-            });
+            }));
 
             Ok(galaxy)
         }
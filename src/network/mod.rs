@@ -21,6 +21,14 @@ pub use packet_header::PacketHeader;
 mod packet;
 pub use packet::Packet;
 
+/// The internal types used by the benches in `benches/`. Not part of the public API.
+#[cfg(feature = "bench-internals")]
+#[doc(hidden)]
+pub mod bench {
+    pub use super::packet::MultiPacketBuffer;
+    pub use crate::utils::spatial_grid::SpatialGrid;
+}
+
 mod packet_reader;
 pub use packet_reader::PacketReader;
 
@@ -30,9 +38,15 @@ pub use packet_writer::PacketWriter;
 mod connection;
 pub use connection::*;
 
+mod connection_driver;
+pub use connection_driver::*;
+
 mod session;
 pub use session::*;
 
+mod tick_gate;
+pub(crate) use tick_gate::*;
+
 mod invalid_argument_kind;
 pub use invalid_argument_kind::*;
 
@@ -46,7 +60,7 @@ use async_channel::Receiver;
 use std::fmt::Write;
 use std::sync::Arc;
 
-#[instrument(level = "trace", skip(f))]
+#[instrument(level = "trace", skip(f, executor))]
 pub(crate) async fn connect(
     uri: &str,
     auth: &str,
@@ -54,6 +68,7 @@ pub(crate) async fn connect(
     runtime_disclosure: Option<RuntimeDisclosure>,
     build_disclosure: Option<BuildDisclosure>,
     f: impl FnOnce(ConnectionHandle, Receiver<FlattiverseEvent>) -> Arc<Galaxy>,
+    executor: impl FnOnce(ConnectionDriver),
 ) -> Result<Arc<Galaxy>, ConnectError> {
     let url = {
         let mut url = String::new();
@@ -95,13 +110,13 @@ pub(crate) async fn connect(
         any(target_arch = "wasm32", target_arch = "wasm64"),
         target_os = "unknown"
     ))]
-    return driver_wasm::connect(&url, f).await;
+    return driver_wasm::connect(&url, f, executor).await;
 
     #[cfg(not(all(
         any(target_arch = "wasm32", target_arch = "wasm64"),
         target_os = "unknown"
     )))]
-    return driver::connect(&url, f).await;
+    return driver::connect(&url, f, executor).await;
 }
 
 #[derive(Debug, thiserror::Error)]
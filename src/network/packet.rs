@@ -1,5 +1,5 @@
 use crate::network::{PacketHeader, PacketReader, PacketWriter};
-use bytes::{BufMut, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 
 pub const SERVER_DEFAULT_PACKET_SIZE: usize = 1052;
 
@@ -12,6 +12,14 @@ impl From<BytesMut> for MultiPacketBuffer {
     }
 }
 
+impl From<Vec<u8>> for MultiPacketBuffer {
+    /// Takes over the allocation of the given [`Vec`] without copying its content.
+    #[inline]
+    fn from(value: Vec<u8>) -> Self {
+        Self(BytesMut::from(Bytes::from(value)))
+    }
+}
+
 impl MultiPacketBuffer {
     pub fn next_packet(&mut self) -> Option<Packet> {
         let header = self.next_header()?;
@@ -0,0 +1,97 @@
+use crate::utils::Atomic;
+use std::sync::Mutex;
+use tokio::sync::Notify;
+
+/// Lockstep between the connection driver and the consumer of
+/// [`crate::galaxy_hierarchy::Galaxy::next_tick_frame`].
+///
+/// While enabled, each applied tick closes the gate until the [`crate::TickFrame`] of that tick
+/// has been released. The driver keeps reading meanwhile, but defers all packets except replies
+/// to commands, so the mirror does not change while a frame is being worked on.
+#[derive(Debug, Default)]
+pub(crate) struct TickGate {
+    enabled: Atomic<bool>,
+    /// The tick whose frame has to be released before further packets are applied.
+    held: Mutex<Option<u32>>,
+    released: Notify,
+}
+
+impl TickGate {
+    #[inline]
+    pub(crate) fn enable(&self) {
+        self.enabled.store(true);
+    }
+
+    /// Disables the lockstep and lets a waiting driver continue.
+    #[inline]
+    pub(crate) fn disable(&self) {
+        self.enabled.store(false);
+        *self.held.lock().unwrap() = None;
+        self.released.notify_waiters();
+    }
+
+    #[inline]
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.load()
+    }
+
+    /// Called while applying a tick to the mirror. If enabled, the gate stays closed until the
+    /// frame of this tick is released. A later tick, applied because a reply to a command
+    /// required it, takes over.
+    #[inline]
+    pub(crate) fn hold(&self, tick: u32) {
+        if self.is_enabled() {
+            *self.held.lock().unwrap() = Some(tick);
+        }
+    }
+
+    /// Whether the gate waits for the frame of this tick. Frames of other ticks were applied
+    /// before the lockstep started or have already been overtaken by the mirror.
+    #[inline]
+    pub(crate) fn holds(&self, tick: u32) -> bool {
+        *self.held.lock().unwrap() == Some(tick)
+    }
+
+    /// Called by the consumer once it is done with a tick. Opens the gate if it waits for this
+    /// tick.
+    #[inline]
+    pub(crate) fn release(&self, tick: u32) {
+        let mut held = self.held.lock().unwrap();
+        if *held == Some(tick) {
+            *held = None;
+            drop(held);
+            self.released.notify_waiters();
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) fn is_open(&self) -> bool {
+        self.held.lock().unwrap().is_none()
+    }
+
+    /// Waits until the gate is open.
+    #[cfg_attr(
+        all(
+            any(target_arch = "wasm32", target_arch = "wasm64"),
+            target_os = "unknown"
+        ),
+        allow(unused)
+    )] // TODO the JS-WebSocket callbacks cannot be suspended
+    pub(crate) async fn opened(&self) {
+        loop {
+            // registered before checking, so no release in between can be missed
+            let released = self.released.notified();
+            if self.is_open() {
+                return;
+            }
+            released.await;
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::future::Future;
 use std::time::SystemTime;
 
+/// Spawns the future on the default executor of the target: `spawn_local` for wasm and the
+/// tokio runtime of the current context otherwise. Any tokio runtime flavor works.
 #[cfg(all(
     any(target_arch = "wasm32", target_arch = "wasm64"),
     target_os = "unknown"
@@ -9,6 +11,8 @@ pub fn spawn(f: impl Future<Output = ()> + 'static) {
     wasm_bindgen_futures::spawn_local(f);
 }
 
+/// Spawns the future on the default executor of the target: `spawn_local` for wasm and the
+/// tokio runtime of the current context otherwise. Any tokio runtime flavor works.
 #[cfg(not(all(
     any(target_arch = "wasm32", target_arch = "wasm64"),
     target_os = "unknown"
@@ -14,6 +14,7 @@ use num_enum::FromPrimitive;
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    serde::Serialize,
 )]
 pub enum SubsystemSlot {
     /// Primary energy battery slot.
@@ -55,6 +55,8 @@ impl Unit for AiBase {
     }
 }
 
+unit_display!(AiBase);
+
 impl NpcUnitInternal for AiBase {
     #[inline]
     fn parent(&self) -> &dyn NpcUnit {
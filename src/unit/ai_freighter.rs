@@ -102,6 +102,8 @@ impl Unit for AiFreighter {
     }
 }
 
+unit_display!(AiFreighter, [metal, carbon, hydrogen, silicon]);
+
 impl MobileUnitInternal for AiFreighter {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
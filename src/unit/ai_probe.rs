@@ -60,6 +60,8 @@ impl Unit for AiProbe {
     }
 }
 
+unit_display!(AiProbe);
+
 impl MobileUnitInternal for AiProbe {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -60,6 +60,8 @@ impl Unit for AiShip {
     }
 }
 
+unit_display!(AiShip);
+
 impl MobileUnitInternal for AiShip {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
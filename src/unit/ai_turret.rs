@@ -55,6 +55,8 @@ impl Unit for AiTurret {
     }
 }
 
+unit_display!(AiTurret);
+
 impl NpcUnitInternal for AiTurret {
     #[inline]
     fn parent(&self) -> &dyn NpcUnit {
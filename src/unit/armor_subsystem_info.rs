@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of an armor subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArmorSubsystemInfo {
     exists: Atomic<bool>,
     reduction: Atomic<f32>,
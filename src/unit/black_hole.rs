@@ -84,6 +84,8 @@ impl Unit for BlackHole {
     }
 }
 
+unit_display!(BlackHole, [gravity_well_radius], [gravity_well_force]);
+
 impl SteadyUnitInternal for BlackHole {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -1,11 +1,12 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable, UnitHierarchy,
-    UnitInternal, UnitKind,
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable,
+    UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::GameError;
 use arc_swap::{ArcSwapOption, Guard};
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// A buoy.
@@ -94,6 +95,16 @@ impl Unit for Buoy {
     }
 }
 
+impl Display for Buoy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        if let Some(message) = self.message().as_ref() {
+            write!(f, " message={:?}", message.as_str())?;
+        }
+        Ok(())
+    }
+}
+
 impl SteadyUnitInternal for Buoy {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -61,6 +61,8 @@ impl Unit for CarbonCargoPowerUp {
     }
 }
 
+unit_display!(CarbonCargoPowerUp);
+
 impl SteadyUnitInternal for CarbonCargoPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
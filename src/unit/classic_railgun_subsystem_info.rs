@@ -5,6 +5,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a railgun subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicRailgunSubsystemInfo {
     exists: Atomic<bool>,
     energy_cost: Atomic<f32>,
@@ -4,6 +4,7 @@ use crate::{SubsystemStatus, Vector};
 
 /// Visible snapshot of a classic-ship engine subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicShipEngineSubsystemInfo {
     exists: Atomic<bool>,
     maximum: Atomic<f32>,
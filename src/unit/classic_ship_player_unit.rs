@@ -220,6 +220,8 @@ impl Unit for ClassicShipPlayerUnit {
     }
 }
 
+unit_display!(ClassicShipPlayerUnit);
+
 impl MobileUnitInternal for ClassicShipPlayerUnit {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -112,6 +112,8 @@ impl Unit for CurrentField {
     }
 }
 
+unit_display!(CurrentField, [mode, flow], [radial_force, tangential_force]);
+
 impl SteadyUnitInternal for CurrentField {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
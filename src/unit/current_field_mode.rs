@@ -14,6 +14,9 @@ use num_enum::FromPrimitive;
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum CurrentFieldMode {
     /// Applies a fixed world-space movement vector.
@@ -103,6 +103,12 @@ impl Unit for DominationPoint {
     }
 }
 
+unit_display!(
+    DominationPoint,
+    [domination, score_countdown],
+    [domination_radius]
+);
+
 impl SteadyUnitInternal for DominationPoint {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
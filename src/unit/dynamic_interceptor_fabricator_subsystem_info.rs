@@ -5,6 +5,11 @@ use std::ops::Deref;
 /// Its semantics are identical to [`DynamicShotFabricatorSubsystemInfo`], but it fabricates
 /// interceptor ammunition instead of shot ammunition.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "unit-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct DynamicInterceptorFabricatorSubsystemInfo(DynamicShotFabricatorSubsystemInfo);
 
 impl Deref for DynamicInterceptorFabricatorSubsystemInfo {
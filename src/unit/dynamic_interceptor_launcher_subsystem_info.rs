@@ -5,6 +5,11 @@ use std::ops::Deref;
 /// Its semantics are identical to [`DynamicShotLauncherSubsystemInfo`], but the launched projectile
 /// type is an interceptor instead of a shot.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "unit-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct DynamicInterceptorLauncherSubsystemInfo(DynamicShotLauncherSubsystemInfo);
 
 impl Deref for DynamicInterceptorLauncherSubsystemInfo {
@@ -5,6 +5,11 @@ use std::ops::Deref;
 /// Its semantics are identical to [`DynamicShotMagazineSubsystemInfo`], but the ammunition consists
 /// of interceptors instead of shots.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "unit-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct DynamicInterceptorMagazineSubsystemInfo(DynamicShotMagazineSubsystemInfo);
 
 impl Deref for DynamicInterceptorMagazineSubsystemInfo {
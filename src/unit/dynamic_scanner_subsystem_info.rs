@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a dynamic scanner subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicScannerSubsystemInfo {
     exists: Atomic<bool>,
     maximum_width: Atomic<f32>,
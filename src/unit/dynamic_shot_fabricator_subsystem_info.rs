@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a dynamic shot fabricator subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicShotFabricatorSubsystemInfo {
     exists: Atomic<bool>,
     maximum_rate: Atomic<f32>,
@@ -6,6 +6,7 @@ use crate::{SubsystemStatus, Vector};
 /// The launcher stores the currently configured projectile profile that would be used for the next
 /// shot.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicShotLauncherSubsystemInfo {
     exists: Atomic<bool>,
     minimum_relative_movement: Atomic<f32>,
@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a dynamic shot magazine subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicShotMagazineSubsystemInfo {
     exists: Atomic<bool>,
     maximum_shots: Atomic<f32>,
@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of an energy-cell
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnergyCellSubsystemInfo {
     exists: Atomic<bool>,
     efficiency: Atomic<f32>,
@@ -61,6 +61,8 @@ impl Unit for EnergyChargePowerUp {
     }
 }
 
+unit_display!(EnergyChargePowerUp);
+
 impl SteadyUnitInternal for EnergyChargePowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -171,6 +171,8 @@ impl Unit for AbstractExplosion {
     }
 }
 
+unit_display!(AbstractExplosion);
+
 #[forbid(clippy::missing_trait_methods)]
 impl ExplosionInternal for AbstractExplosion {
     #[inline]
@@ -88,6 +88,8 @@ impl Unit for Flag {
     }
 }
 
+unit_display!(Flag, [active], [grace_ticks]);
+
 impl SteadyUnitInternal for Flag {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -1,11 +1,12 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable, UnitHierarchy,
-    UnitInternal, UnitKind,
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable,
+    UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::{Also, Atomic};
 use crate::GameError;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 #[derive(Debug, Clone)]
@@ -132,6 +133,28 @@ impl Unit for Gate {
     }
 }
 
+impl Display for Gate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(f, " closed={}", self.closed())?;
+        if f.alternate() {
+            write!(
+                f,
+                " linked_id={} default_closed={}",
+                self.linked_it(),
+                self.default_closed()
+            )?;
+            if let Some(ticks) = self.restore_ticks() {
+                write!(f, " restore_ticks={ticks}")?;
+            }
+            if let Some(ticks) = self.restore_remaining_ticks() {
+                write!(f, " restore_remaining_ticks={ticks}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl SteadyUnitInternal for Gate {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -61,6 +61,8 @@ impl Unit for HullRepairPowerUp {
     }
 }
 
+unit_display!(HullRepairPowerUp);
+
 impl SteadyUnitInternal for HullRepairPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
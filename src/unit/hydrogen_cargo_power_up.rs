@@ -61,6 +61,8 @@ impl Unit for HydrogenCargoPowerUp {
     }
 }
 
+unit_display!(HydrogenCargoPowerUp);
+
 impl SteadyUnitInternal for HydrogenCargoPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
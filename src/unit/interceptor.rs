@@ -68,6 +68,8 @@ impl Unit for Interceptor {
     }
 }
 
+unit_display!(Interceptor);
+
 impl MobileUnitInternal for Interceptor {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
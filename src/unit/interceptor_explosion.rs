@@ -55,6 +55,8 @@ impl Unit for InterceptorExplosion {
     }
 }
 
+unit_display!(InterceptorExplosion);
+
 impl ExplosionInternal for InterceptorExplosion {
     fn parent(&self) -> &dyn Explosion {
         &self.parent
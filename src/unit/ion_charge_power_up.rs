@@ -61,6 +61,8 @@ impl Unit for IonChargePowerUp {
     }
 }
 
+unit_display!(IonChargePowerUp);
+
 impl SteadyUnitInternal for IonChargePowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -3,6 +3,7 @@ use crate::utils::Atomic;
 
 /// Visible snapshot of a jump-drive subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JumpDriveSubsystemInfo {
     exists: Atomic<bool>,
     energy_cost: Atomic<f32>,
@@ -61,6 +61,8 @@ impl Unit for MetalCargoPowerUp {
     }
 }
 
+unit_display!(MetalCargoPowerUp);
+
 impl SteadyUnitInternal for MetalCargoPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
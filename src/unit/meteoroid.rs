@@ -1,12 +1,13 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable, UnitHierarchy,
-    UnitInternal, UnitKind,
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable,
+    UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::Atomic;
 use crate::GameError;
 use num_enum::FromPrimitive;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// Meteoroid map unit that can act as a mining target.
@@ -111,6 +112,21 @@ impl Unit for Meteoroid {
     }
 }
 
+impl Display for Meteoroid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(
+            f,
+            " type={} metal={} carbon={} hydrogen={} silicon={}",
+            self.r#type(),
+            self.metal(),
+            self.carbon(),
+            self.hydrogen(),
+            self.silicon()
+        )
+    }
+}
+
 impl SteadyUnitInternal for Meteoroid {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -132,6 +148,9 @@ impl SteadyUnit for Meteoroid {}
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum MeteoroidType {
     /// # Summary
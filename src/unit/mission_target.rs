@@ -1,12 +1,13 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractTargetUnit, SteadyUnit, SteadyUnitInternal, TargetUnit, TargetUnitInternal, Unit,
-    UnitCastTable, UnitHierarchy, UnitInternal, UnitKind,
+    fmt_unit, AbstractTargetUnit, SteadyUnit, SteadyUnitInternal, TargetUnit, TargetUnitInternal,
+    Unit, UnitCastTable, UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::Atomic;
 use crate::{GameError, Vector};
 use arc_swap::{ArcSwap, Guard};
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// A mission target with a sequence number and configurable waypoint vectors.
@@ -105,6 +106,24 @@ impl Unit for MissionTarget {
     }
 }
 
+impl Display for MissionTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(f, " sequence_number={}", self.sequence_number())?;
+        if f.alternate() {
+            write!(f, " vectors=[")?;
+            for (index, vector) in self.vectors().iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{vector}")?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
 impl SteadyUnitInternal for MissionTarget {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
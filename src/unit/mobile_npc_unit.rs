@@ -111,6 +111,8 @@ impl Unit for AbstractMobileNpcUnit {
     }
 }
 
+unit_display!(AbstractMobileNpcUnit);
+
 impl MobileUnitInternal for AbstractMobileNpcUnit {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -1,4 +1,4 @@
-use crate::galaxy_hierarchy::Cluster;
+use crate::galaxy_hierarchy::{Cluster, MotionHistory, MotionPrediction};
 use crate::network::PacketReader;
 use crate::unit::{AbstractUnit, Mobility, Unit, UnitCastTable, UnitHierarchy, UnitInternal};
 use crate::utils::Atomic;
@@ -17,6 +17,20 @@ pub trait MobileUnit: MobileUnitInternal + Unit {
     fn angular_velocity(&self) -> f32 {
         MobileUnitInternal::parent(self).angular_velocity()
     }
+
+    /// The recent movement updates of this unit, see
+    /// [`crate::galaxy_hierarchy::Cluster::motion_history`].
+    #[inline]
+    fn motion_history(&self) -> Option<MotionHistory> {
+        MobileUnitInternal::parent(self).motion_history()
+    }
+
+    /// The position `ticks_ahead` ticks after the latest movement update, see
+    /// [`MotionHistory::predicted_position`]. `None` if no motion history was recorded.
+    #[inline]
+    fn predicted_position(&self, ticks_ahead: u32) -> Option<MotionPrediction> {
+        MobileUnitInternal::parent(self).predicted_position(ticks_ahead)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -92,6 +106,8 @@ impl Unit for AbstractMobileUnit {
     }
 }
 
+unit_display!(AbstractMobileUnit);
+
 #[forbid(clippy::missing_trait_methods)]
 impl MobileUnitInternal for AbstractMobileUnit {
     fn parent(&self) -> &dyn MobileUnit {
@@ -105,4 +121,18 @@ impl MobileUnit for AbstractMobileUnit {
     fn angular_velocity(&self) -> f32 {
         self.angular_velocity.load()
     }
+
+    #[inline]
+    fn motion_history(&self) -> Option<MotionHistory> {
+        self.cluster().motion_history(self.name())
+    }
+
+    #[inline]
+    fn predicted_position(&self, ticks_ahead: u32) -> Option<MotionPrediction> {
+        self.cluster()
+            .with_motion_history(self.name(), |history| {
+                history.predicted_position(ticks_ahead)
+            })
+            .flatten()
+    }
 }
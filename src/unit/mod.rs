@@ -12,6 +12,25 @@ macro_rules! cast_fn {
     };
 }
 
+/// Implements [`std::fmt::Display`] for a unit type with [`crate::unit::fmt_unit`], followed by
+/// the given values of the kind and, for the alternate form `{:#}`, the second list of values.
+macro_rules! unit_display {
+    ($ty:ty $(, [$($value:ident),* $(,)?] $(, [$($detail:ident),* $(,)?])?)?) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                crate::unit::fmt_unit(self, f)?;
+                $(
+                    $(write!(f, concat!(" ", stringify!($value), "={}"), self.$value())?;)*
+                    $(if f.alternate() {
+                        $(write!(f, concat!(" ", stringify!($detail), "={}"), self.$detail())?;)*
+                    })?
+                )?
+                Ok(())
+            }
+        }
+    };
+}
+
 mod kind;
 pub use kind::*;
 
@@ -174,6 +193,14 @@ pub use current_field::*;
 mod current_field_mode;
 pub use current_field_mode::*;
 
+mod unit_change;
+pub use unit_change::*;
+
+#[cfg(feature = "unit-serde")]
+mod unit_snapshot;
+#[cfg(feature = "unit-serde")]
+pub use unit_snapshot::*;
+
 mod armor_subsystem_info;
 pub use armor_subsystem_info::*;
 
@@ -3,6 +3,11 @@ use std::ops::Deref;
 
 /// Visible snapshot of a modern railgun subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "unit-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ModernRailgunSubsystemInfo(ClassicRailgunSubsystemInfo);
 
 impl Deref for ModernRailgunSubsystemInfo {
@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a modern-ship engine subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModernShipEngineSubsystemInfo {
     exists: Atomic<bool>,
     maximum_thrust: Atomic<f32>,
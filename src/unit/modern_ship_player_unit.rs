@@ -223,6 +223,8 @@ impl Unit for ModernShipPlayerUnit {
     }
 }
 
+unit_display!(ModernShipPlayerUnit);
+
 impl MobileUnitInternal for ModernShipPlayerUnit {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
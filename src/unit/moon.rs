@@ -2,10 +2,13 @@ use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::steady::SteadyUnitInternal;
 use crate::unit::unit::{Unit, UnitInternal};
-use crate::unit::{AbstractSteadyUnit, SteadyUnit, UnitCastTable, UnitHierarchy, UnitKind};
+use crate::unit::{
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, UnitCastTable, UnitHierarchy, UnitKind,
+};
 use crate::utils::Atomic;
 use crate::GameError;
 use num_enum::FromPrimitive;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// Moon map unit that can act as a mining target.
@@ -110,6 +113,21 @@ impl Unit for Moon {
     }
 }
 
+impl Display for Moon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(
+            f,
+            " type={} metal={} carbon={} hydrogen={} silicon={}",
+            self.r#type(),
+            self.metal(),
+            self.carbon(),
+            self.hydrogen(),
+            self.silicon()
+        )
+    }
+}
+
 impl SteadyUnitInternal for Moon {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -131,6 +149,9 @@ impl SteadyUnit for Moon {}
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum MoonType {
     /// # Summary
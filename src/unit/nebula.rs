@@ -83,6 +83,8 @@ impl Unit for Nebula {
     }
 }
 
+unit_display!(Nebula, [hue]);
+
 impl SteadyUnitInternal for Nebula {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
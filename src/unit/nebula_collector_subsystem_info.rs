@@ -4,6 +4,7 @@ use crate::SubsystemStatus;
 
 /// Visible snapshot of a nebula collector subsystem on a scanned player unit.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "unit-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NebulaCollectorSubsystemInfo {
     exists: Atomic<bool>,
     minimum_rate: Atomic<f32>,
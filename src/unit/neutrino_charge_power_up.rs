@@ -61,6 +61,8 @@ impl Unit for NeutrinoChargePowerUp {
     }
 }
 
+unit_display!(NeutrinoChargePowerUp);
+
 impl SteadyUnitInternal for NeutrinoChargePowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -99,6 +99,8 @@ impl Unit for AbstractNpcUnit {
     }
 }
 
+unit_display!(AbstractNpcUnit);
+
 #[forbid(clippy::missing_trait_methods)]
 impl NpcUnitInternal for AbstractNpcUnit {
     fn parent(&self) -> &dyn NpcUnit {
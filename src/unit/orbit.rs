@@ -1,7 +1,7 @@
 use crate::Vector;
 
 /// One orbit segment around the configured center or the preceding orbit segment.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Orbit {
     distance: f32,
     start_angle: f32,
@@ -38,11 +38,34 @@ impl Orbit {
 
     /// Calculates the offset contributed by this orbit segment at the given galaxy tick.
     /// The caller applies this offset relative to the current orbit center in the chain.
+    /// An orbit without rotation ticks stays at its start angle.
     pub fn calculate_offset(&self, tick: u32) -> Vector {
-        #[allow(clippy::cast_abs_to_unsigned)]
-        let interval = i64::from(self.rotation_ticks).abs() as u32;
-        let phase_tick = tick % interval;
+        match self.interval() {
+            Some(interval) => self.offset_at_phase(tick % interval),
+            None => Vector::from_angle_length(self.start_angle, self.distance),
+        }
+    }
+
+    /// Calculates the distance this orbit segment moved from the tick before to the given galaxy
+    /// tick. The tick before is taken within the rotation, so tick 0 follows its last tick.
+    pub fn calculate_movement(&self, tick: u32) -> Vector {
+        match self.interval() {
+            Some(interval) => {
+                let phase = tick % interval;
+                let previous = phase.checked_sub(1).unwrap_or(interval - 1);
+                self.offset_at_phase(phase) - self.offset_at_phase(previous)
+            }
+            None => Vector::default(),
+        }
+    }
 
+    /// The ticks of a full rotation, `None` for an orbit which does not rotate.
+    #[inline]
+    fn interval(&self) -> Option<u32> {
+        (self.rotation_ticks != 0).then(|| self.rotation_ticks.unsigned_abs())
+    }
+
+    fn offset_at_phase(&self, phase_tick: u32) -> Vector {
         let mut angle =
             (self.start_angle + 360.0 * (phase_tick as f32) / (self.rotation_ticks as f32)) % 360.0;
 
@@ -52,4 +75,130 @@ impl Orbit {
 
         Vector::from_angle_length(angle, self.distance)
     }
+
+    /// Applies the whole orbit chain to the configured center, see
+    /// [`crate::unit::SteadyUnit::orbiting_list`], and returns the position at the given galaxy
+    /// tick.
+    pub fn chain_position(center: Vector, orbits: &[Orbit], tick: u32) -> Vector {
+        orbits.iter().fold(center, |position, orbit| {
+            position + orbit.calculate_offset(tick)
+        })
+    }
+
+    /// The distance travelled along the whole orbit chain from the tick before to the given
+    /// galaxy tick.
+    pub fn chain_movement(orbits: &[Orbit], tick: u32) -> Vector {
+        orbits.iter().fold(Vector::default(), |movement, orbit| {
+            movement + orbit.calculate_movement(tick)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::PacketWriter;
+    use crate::unit::unit::UnitInternal;
+    use crate::unit::{AbstractSteadyUnit, SteadyUnit};
+    use bytes::BytesMut;
+    use std::sync::Weak;
+
+    fn assert_near(actual: Vector, expected: Vector) {
+        assert!(
+            (actual - expected).length() < 0.001,
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    /// A planet at (100, 0) circled by a moon with a distance of 10 every 4 ticks, which in turn
+    /// is circled clockwise every 2 ticks.
+    fn orbits() -> Vec<Orbit> {
+        vec![Orbit::new(10.0, 0.0, 4), Orbit::new(1.0, 90.0, -2)]
+    }
+
+    fn steady_unit(orbits: &[Orbit]) -> AbstractSteadyUnit {
+        let mut reader = BytesMut::new();
+        reader.write_f32(-5.0); // position
+        reader.write_f32(-5.0);
+        reader.write_f32(1.0); // radius
+        reader.write_f32(0.0); // gravity
+        let unit = AbstractSteadyUnit::new(Weak::new(), "unit".to_string(), &mut reader).unwrap();
+
+        reader.write_f32(100.0); // configured position
+        reader.write_f32(0.0);
+        reader.write_byte(orbits.len() as u8);
+        for orbit in orbits {
+            reader.write_f32(orbit.distance());
+            reader.write_f32(orbit.start_angle());
+            reader.write_int32(orbit.rotation_ticks());
+        }
+        unit.update_state(&mut reader);
+        unit
+    }
+
+    #[test]
+    fn chain_position() {
+        let center = Vector::new(100.0, 0.0);
+        let orbits = orbits();
+
+        assert_near(
+            Orbit::chain_position(center, &orbits, 0),
+            Vector::new(110.0, 1.0),
+        );
+        assert_near(
+            Orbit::chain_position(center, &orbits, 1),
+            Vector::new(100.0, 9.0),
+        );
+        assert_near(
+            Orbit::chain_position(center, &orbits, 2),
+            Vector::new(90.0, 1.0),
+        );
+        assert_near(
+            Orbit::chain_position(center, &orbits, 7),
+            Vector::new(100.0, -11.0),
+        );
+        assert_near(Orbit::chain_position(center, &[], 7), center);
+    }
+
+    #[test]
+    fn position_at() {
+        let unit = steady_unit(&orbits());
+        assert_near(unit.position_at(1), Vector::new(100.0, 9.0));
+        assert_near(unit.position_at(4), Vector::new(110.0, 1.0));
+
+        let unit = steady_unit(&[]);
+        assert_near(unit.position_at(1), Vector::new(-5.0, -5.0));
+    }
+
+    #[test]
+    fn movement_at() {
+        let unit = steady_unit(&orbits());
+        assert_near(unit.movement_at(1), Vector::new(-10.0, 8.0));
+        // the tick before tick 0 is the last tick of the rotation, not u32::MAX
+        assert_near(unit.movement_at(0), Vector::new(10.0, 12.0));
+        assert_near(unit.movement_at(4), unit.movement_at(0));
+
+        let orbits = [Orbit::new(10.0, 0.0, 3)];
+        let full_rotation = (1..=3).fold(Vector::default(), |sum, tick| {
+            sum + Orbit::chain_movement(&orbits, tick)
+        });
+        assert_near(full_rotation, Vector::default());
+    }
+
+    #[test]
+    fn prediction_deviation() {
+        let unit = steady_unit(&[Orbit::new(10.0, 0.0, 4)]);
+        let mut reader = BytesMut::new();
+        reader.write_f32(100.0); // position
+        reader.write_f32(10.0);
+        reader.write_f32(-10.0); // movement
+        reader.write_f32(10.0);
+        unit.update_movement(&mut reader);
+
+        assert!(unit.prediction_deviation(1).unwrap() < 0.001);
+        assert!((unit.prediction_deviation(3).unwrap() - 20.0).abs() < 0.001);
+
+        let unit = steady_unit(&[]);
+        assert_eq!(unit.prediction_deviation(1), None);
+    }
 }
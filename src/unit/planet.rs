@@ -1,12 +1,13 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable, UnitHierarchy,
-    UnitInternal, UnitKind,
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable,
+    UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::Atomic;
 use crate::GameError;
 use num_enum::FromPrimitive;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// Planetary map unit that can act as a mining target.
@@ -112,6 +113,21 @@ impl Unit for Planet {
     }
 }
 
+impl Display for Planet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(
+            f,
+            " type={} metal={} carbon={} hydrogen={} silicon={}",
+            self.r#type(),
+            self.metal(),
+            self.carbon(),
+            self.hydrogen(),
+            self.silicon()
+        )
+    }
+}
+
 impl SteadyUnitInternal for Planet {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
@@ -133,6 +149,9 @@ impl SteadyUnit for Planet {}
     num_enum::IntoPrimitive,
     strum::EnumIter,
     strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum PlanetType {
     /// # Summary
@@ -233,6 +233,8 @@ impl Unit for AbstractPlayerUnit {
     }
 }
 
+unit_display!(AbstractPlayerUnit);
+
 impl MobileUnitInternal for AbstractPlayerUnit {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -88,6 +88,8 @@ impl Unit for AbstractPowerUp {
     }
 }
 
+unit_display!(AbstractPowerUp);
+
 impl SteadyUnitInternal for AbstractPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
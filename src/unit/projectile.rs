@@ -151,6 +151,8 @@ impl Unit for AbstractProjectile {
     }
 }
 
+unit_display!(AbstractProjectile);
+
 impl MobileUnitInternal for AbstractProjectile {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
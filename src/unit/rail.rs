@@ -68,6 +68,8 @@ impl Unit for Rail {
     }
 }
 
+unit_display!(Rail);
+
 impl MobileUnitInternal for Rail {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
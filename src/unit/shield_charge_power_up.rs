@@ -61,6 +61,8 @@ impl Unit for ShieldChargePowerUp {
     }
 }
 
+unit_display!(ShieldChargePowerUp);
+
 impl SteadyUnitInternal for ShieldChargePowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
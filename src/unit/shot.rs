@@ -68,6 +68,8 @@ impl Unit for Shot {
     }
 }
 
+unit_display!(Shot);
+
 impl MobileUnitInternal for Shot {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
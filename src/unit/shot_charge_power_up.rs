@@ -61,6 +61,8 @@ impl Unit for ShotChargePowerUp {
     }
 }
 
+unit_display!(ShotChargePowerUp);
+
 impl SteadyUnitInternal for ShotChargePowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
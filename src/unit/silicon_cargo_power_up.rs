@@ -61,6 +61,8 @@ impl Unit for SiliconCargoPowerUp {
     }
 }
 
+unit_display!(SiliconCargoPowerUp);
+
 impl SteadyUnitInternal for SiliconCargoPowerUp {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
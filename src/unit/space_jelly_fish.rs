@@ -54,6 +54,8 @@ impl Unit for SpaceJellyFish {
     }
 }
 
+unit_display!(SpaceJellyFish);
+
 impl MobileUnitInternal for SpaceJellyFish {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -1,13 +1,14 @@
 use crate::galaxy_hierarchy::Cluster;
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractProjectile, MobileUnit, MobileUnitInternal, Projectile, ProjectileInternal, Unit,
-    UnitCastTable, UnitHierarchy, UnitInternal, UnitKind,
+    fmt_unit, AbstractProjectile, MobileUnit, MobileUnitInternal, Projectile, ProjectileInternal,
+    Unit, UnitCastTable, UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::Atomic;
 use crate::GameError;
 use arc_swap::ArcSwap;
 use num_enum::FromPrimitive;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// Homing biological projectile spawned by a space jellyfish.
@@ -121,6 +122,20 @@ impl Unit for SpaceJellyFishSlime {
     }
 }
 
+impl Display for SpaceJellyFishSlime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        write!(f, " target={:?}", self.target_unit_name().as_str())?;
+        if f.alternate() {
+            write!(f, " target_cluster_id={}", self.target_cluster_id())?;
+            if let Some(kind) = self.target_unit_kind() {
+                write!(f, " target_kind={kind}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl MobileUnitInternal for SpaceJellyFishSlime {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -3,6 +3,11 @@ use std::ops::Deref;
 
 /// Visible snapshot of a static shot magazine subsystem on a scanned modern ship.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "unit-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct StaticShotMagazineSubsystemInfo(DynamicShotMagazineSubsystemInfo);
 
 impl Deref for StaticShotMagazineSubsystemInfo {
@@ -28,6 +28,28 @@ pub trait SteadyUnit: SteadyUnitInternal + Unit {
     fn orbiting_list(&self) -> Arc<Vec<Orbit>> {
         SteadyUnitInternal::parent(self).orbiting_list()
     }
+
+    /// Predicted position at the given galaxy tick, following the orbit chain. Units without
+    /// orbits stay at their current position.
+    #[inline]
+    fn position_at(&self, tick: u32) -> Vector {
+        SteadyUnitInternal::parent(self).position_at(tick)
+    }
+
+    /// Predicted movement at the given galaxy tick, the distance travelled since the tick before.
+    /// Units without orbits keep their current movement.
+    #[inline]
+    fn movement_at(&self, tick: u32) -> Vector {
+        SteadyUnitInternal::parent(self).movement_at(tick)
+    }
+
+    /// Distance between the observed position and [`SteadyUnit::position_at`] the given tick,
+    /// which should be close to zero for the current tick. `None` for units without orbits, which
+    /// includes units whose full state is not known yet.
+    #[inline]
+    fn prediction_deviation(&self, tick: u32) -> Option<f32> {
+        SteadyUnitInternal::parent(self).prediction_deviation(tick)
+    }
 }
 
 #[derive(Debug)]
@@ -143,6 +165,8 @@ impl Unit for AbstractSteadyUnit {
     }
 }
 
+unit_display!(AbstractSteadyUnit);
+
 #[forbid(clippy::missing_trait_methods)]
 impl SteadyUnitInternal for AbstractSteadyUnit {
     #[inline]
@@ -162,4 +186,32 @@ impl SteadyUnit for AbstractSteadyUnit {
     fn orbiting_list(&self) -> Arc<Vec<Orbit>> {
         self.orbiting_list.load_full()
     }
+
+    fn position_at(&self, tick: u32) -> Vector {
+        let orbits = self.orbiting_list.load();
+        if orbits.is_empty() {
+            self.position()
+        } else {
+            Orbit::chain_position(self.configured_position(), &orbits, tick)
+        }
+    }
+
+    fn movement_at(&self, tick: u32) -> Vector {
+        let orbits = self.orbiting_list.load();
+        if orbits.is_empty() {
+            self.movement()
+        } else {
+            Orbit::chain_movement(&orbits, tick)
+        }
+    }
+
+    fn prediction_deviation(&self, tick: u32) -> Option<f32> {
+        let orbits = self.orbiting_list.load();
+        if orbits.is_empty() {
+            None
+        } else {
+            let predicted = Orbit::chain_position(self.configured_position(), &orbits, tick);
+            Some((self.position() - predicted).length())
+        }
+    }
 }
@@ -183,6 +183,24 @@ impl Unit for Storm {
     }
 }
 
+unit_display!(
+    Storm,
+    [damage],
+    [
+        spawn_chance_per_tick,
+        min_announcement_ticks,
+        max_announcement_ticks,
+        min_active_ticks,
+        max_active_ticks,
+        min_whirl_radius,
+        max_whirl_radius,
+        min_whirl_speed,
+        max_whirl_speed,
+        min_whirl_gravity,
+        max_whirl_gravity
+    ]
+);
+
 impl SteadyUnitInternal for Storm {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
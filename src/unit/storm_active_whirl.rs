@@ -76,6 +76,8 @@ impl Unit for StormActiveWhirl {
     }
 }
 
+unit_display!(StormActiveWhirl, [damage]);
+
 impl MobileUnitInternal for StormActiveWhirl {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -71,6 +71,8 @@ impl Unit for StormCommencingWhirl {
     }
 }
 
+unit_display!(StormCommencingWhirl);
+
 impl MobileUnitInternal for StormCommencingWhirl {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
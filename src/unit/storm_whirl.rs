@@ -89,6 +89,8 @@ impl Unit for AbstractStormWhirl {
     }
 }
 
+unit_display!(AbstractStormWhirl);
+
 impl MobileUnitInternal for AbstractStormWhirl {
     #[inline]
     fn parent(&self) -> &dyn MobileUnit {
@@ -115,6 +115,8 @@ impl Unit for Sun {
     }
 }
 
+unit_display!(Sun, [energy], [ions, neutrinos, heat, drain]);
+
 impl SteadyUnitInternal for Sun {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
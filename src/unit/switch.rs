@@ -147,6 +147,12 @@ impl Unit for Switch {
     }
 }
 
+unit_display!(
+    Switch,
+    [switched, mode],
+    [link_id, range, cooldown_ticks, cooldown_remaining_ticks]
+);
+
 impl SteadyUnitInternal for Switch {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
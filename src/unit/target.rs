@@ -99,6 +99,8 @@ impl Unit for AbstractTargetUnit {
     }
 }
 
+unit_display!(AbstractTargetUnit);
+
 impl SteadyUnitInternal for AbstractTargetUnit {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
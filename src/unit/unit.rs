@@ -13,7 +13,7 @@ use crate::unit::{
 use crate::utils::Atomic;
 use crate::Vector;
 use std::any::Any;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 use std::sync::{Arc, Weak};
 
 pub(crate) trait UnitInternal {
@@ -291,7 +291,9 @@ pub trait UnitHierarchy: UnitInternal {
 /// Derived classes add the type-specific data that becomes available once the server has delivered the full state for
 /// the unit.
 #[allow(private_bounds)]
-pub trait Unit: UnitInternal + UnitCastTable + UnitHierarchy + Debug + Send + Sync + Any {
+pub trait Unit:
+    UnitInternal + UnitCastTable + UnitHierarchy + Debug + Display + Send + Sync + Any
+{
     /// Stable protocol name of the unit inside its cluster.
     fn name(&self) -> &str {
         self.parent().name()
@@ -678,6 +680,132 @@ impl UnitHierarchy for AbstractUnit {
     }
 }
 
+/// Writes the part of the [`Display`] representation of a unit which is common to all kinds, like
+/// `Sun "Sol" @ (120.0, -40.0) r=60 g=0.8`, followed by the values of its unit hierarchy. The
+/// alternate form `{:#}` adds the remaining common values.
+pub(crate) fn fmt_unit(unit: &dyn Unit, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+        f,
+        "{} {:?} @ {} r={} g={}",
+        unit.kind(),
+        unit.name(),
+        unit.position(),
+        unit.radius(),
+        unit.gravity()
+    )?;
+
+    if f.alternate() {
+        write!(
+            f,
+            " movement={} angle={} mobility={} cluster={:?}",
+            unit.movement(),
+            unit.angle(),
+            unit.mobility(),
+            &*unit.cluster().name()
+        )?;
+        if let Some(team) = unit.team().upgrade() {
+            write!(f, " team={:?}", &*team.name())?;
+        }
+        if unit.is_masking() {
+            write!(f, " masking")?;
+        }
+        if unit.is_solid() {
+            write!(f, " solid")?;
+        }
+        if !unit.full_state_known() {
+            write!(f, " partial")?;
+        }
+    }
+
+    if let Some(steady) = unit.as_steady_unit() {
+        if f.alternate() {
+            write!(
+                f,
+                " configured_position={} orbits={}",
+                steady.configured_position(),
+                steady.orbiting_list().len()
+            )?;
+        }
+    }
+
+    if let Some(mobile) = unit.as_mobile_unit() {
+        if f.alternate() {
+            write!(f, " angular_velocity={}", mobile.angular_velocity())?;
+        }
+    }
+
+    if let Some(player_unit) = unit.as_player_unit() {
+        write!(
+            f,
+            " player={:?} hull={}/{} shield={}/{}",
+            player_unit.player().name(),
+            player_unit.hull().current(),
+            player_unit.hull().maximum(),
+            player_unit.shield().current(),
+            player_unit.shield().maximum()
+        )?;
+        if f.alternate() {
+            write!(
+                f,
+                " energy={}/{} ions={}/{} neutrinos={}/{} structural_load={}",
+                player_unit.energy_battery().current(),
+                player_unit.energy_battery().maximum(),
+                player_unit.ion_battery().current(),
+                player_unit.ion_battery().maximum(),
+                player_unit.neutrino_battery().current(),
+                player_unit.neutrino_battery().maximum(),
+                player_unit.effective_structural_load()
+            )?;
+        }
+    }
+
+    if let Some(npc) = unit.as_npc_unit() {
+        write!(f, " hull={}/{}", npc.hull(), npc.hull_maximum())?;
+    }
+
+    if let Some(npc) = unit.as_mobile_npc_unit() {
+        write!(f, " hull={}/{}", npc.hull(), npc.hull_max())?;
+    }
+
+    if let Some(power_up) = unit.as_power_up() {
+        write!(f, " amount={}", power_up.amount())?;
+    }
+
+    if let Some(projectile) = unit.as_projectile() {
+        write!(f, " damage={}", projectile.damage())?;
+        if f.alternate() {
+            write!(
+                f,
+                " ticks={} load={}",
+                projectile.ticks(),
+                projectile.load()
+            )?;
+            if let Some(player) = projectile.player().upgrade() {
+                write!(f, " player={:?}", player.name())?;
+            }
+        }
+    }
+
+    if let Some(explosion) = unit.as_explosion() {
+        write!(f, " damage={}", explosion.damage())?;
+        if f.alternate() {
+            write!(
+                f,
+                " damage_phase={} shock_wave_phase={}",
+                explosion.is_damage_phase(),
+                explosion.is_shock_wave_phase()
+            )?;
+            if let Some(player) = explosion.player().upgrade() {
+                write!(f, " player={:?}", player.name())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+unit_display!(AbstractUnit);
+
 #[forbid(clippy::missing_trait_methods)]
 impl Unit for AbstractUnit {
     #[inline]
@@ -813,6 +941,12 @@ pub(crate) trait UnitCastTable {
 }
 
 impl dyn Unit {
+    /// Casts into the concrete unit type, like [`Sun`] or [`ClassicShipPlayerUnit`].
+    #[inline]
+    pub fn into_unit_of<T: Unit>(self: Arc<dyn Unit>) -> Option<Arc<T>> {
+        Arc::downcast::<T>(self).ok()
+    }
+
     #[inline]
     pub fn into_steady_unit(self: Arc<dyn Unit>) -> Option<Arc<dyn SteadyUnit>> {
         self.steady_unit_cast_fn()(self)
@@ -0,0 +1,687 @@
+use crate::galaxy_hierarchy::{ClusterId, RailgunDirection, TeamId};
+use crate::unit::{CurrentFieldMode, Unit, UnitKind};
+use crate::Vector;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// The fields of a unit which changed with an update, see
+/// [`crate::FlattiverseEventKind::UnitUpdated`].
+///
+/// The unit of the event is the live unit of the mirror and may have changed again by the time the
+/// event is handled. The values in here are the ones from right before and right after the update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UnitChange {
+    /// `None` if the position did not change.
+    pub position: Option<Change<Vector>>,
+    /// `None` if the movement did not change.
+    pub movement: Option<Change<Vector>>,
+    /// `None` if the angle did not change.
+    pub angle: Option<Change<f32>>,
+    /// `None` if the team did not change, like it does for a [`crate::unit::DominationPoint`].
+    pub team: Option<Change<Option<TeamId>>>,
+    /// The changed kind specific fields, like [`UnitField::Closed`] of a [`crate::unit::Gate`] or
+    /// [`UnitField::Switched`] of a [`crate::unit::Switch`].
+    pub fields: Vec<UnitFieldChange>,
+}
+
+impl UnitChange {
+    /// Whether the update did not change anything we keep track of.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.position.is_none()
+            && self.movement.is_none()
+            && self.angle.is_none()
+            && self.team.is_none()
+            && self.fields.is_empty()
+    }
+
+    /// The change of the given kind specific field.
+    #[inline]
+    pub fn field(&self, field: UnitField) -> Option<&UnitFieldChange> {
+        self.fields.iter().find(|change| change.field == field)
+    }
+
+    #[inline]
+    pub fn changed(&self, field: UnitField) -> bool {
+        self.field(field).is_some()
+    }
+
+    /// The change of a boolean field like [`UnitField::Closed`].
+    pub fn bool(&self, field: UnitField) -> Option<Change<bool>> {
+        let change = self.field(field)?;
+        Some(Change::new(change.old.as_bool()?, change.new.as_bool()?))
+    }
+
+    /// The change of an integer field like [`UnitField::Ticks`].
+    pub fn integer(&self, field: UnitField) -> Option<Change<i64>> {
+        let change = self.field(field)?;
+        Some(Change::new(
+            change.old.as_integer()?,
+            change.new.as_integer()?,
+        ))
+    }
+
+    /// The change of a float field like [`UnitField::Hull`].
+    pub fn float(&self, field: UnitField) -> Option<Change<f32>> {
+        let change = self.field(field)?;
+        Some(Change::new(change.old.as_float()?, change.new.as_float()?))
+    }
+
+    /// Combines this change with the one of a later update of the same unit. The old values are
+    /// taken from this change and the new values from the later one. Fields which are back at
+    /// their old value are left out.
+    pub(crate) fn merged(&self, later: &UnitChange) -> UnitChange {
+        let mut fields = Vec::with_capacity(self.fields.len() + later.fields.len());
+        for change in &self.fields {
+            match later.field(change.field) {
+                None => fields.push(change.clone()),
+                Some(later) if later.new != change.old => fields.push(UnitFieldChange {
+                    field: change.field,
+                    old: change.old.clone(),
+                    new: later.new.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        fields.extend(
+            later
+                .fields
+                .iter()
+                .filter(|change| !self.changed(change.field))
+                .cloned(),
+        );
+
+        UnitChange {
+            position: Change::merged(&self.position, &later.position),
+            movement: Change::merged(&self.movement, &later.movement),
+            angle: Change::merged(&self.angle, &later.angle),
+            team: Change::merged(&self.team, &later.team),
+            fields,
+        }
+    }
+}
+
+impl Display for UnitChange {
+    /// `field old -> new` for each changed field, separated by commas.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        let mut write = |f: &mut Formatter<'_>, name: &str, change: &dyn Display| {
+            let result = write!(f, "{separator}{name} {change}");
+            separator = ", ";
+            result
+        };
+        if let Some(position) = &self.position {
+            write(f, "position", position)?;
+        }
+        if let Some(movement) = &self.movement {
+            write(f, "movement", movement)?;
+        }
+        if let Some(angle) = &self.angle {
+            write(f, "angle", angle)?;
+        }
+        if let Some(team) = &self.team {
+            let id = |team: Option<TeamId>| UnitValue::from(team.map(|team| team.0));
+            write(f, "team", &Change::new(id(team.old), id(team.new)))?;
+        }
+        for change in &self.fields {
+            write(
+                f,
+                change.field.as_ref(),
+                &Change::new(&change.old, &change.new),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The old and the new value of a changed field.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T> Change<T> {
+    #[inline]
+    pub fn new(old: T, new: T) -> Self {
+        Self { old, new }
+    }
+}
+
+impl<T: Clone + PartialEq> Change<T> {
+    fn merged(first: &Option<Self>, later: &Option<Self>) -> Option<Self> {
+        match (first, later) {
+            (Some(first), Some(later)) => {
+                (first.old != later.new).then(|| Change::new(first.old.clone(), later.new.clone()))
+            }
+            (Some(first), None) => Some(first.clone()),
+            (None, later) => later.clone(),
+        }
+    }
+}
+
+impl<T: Display> Display for Change<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.old, self.new)
+    }
+}
+
+/// A kind specific field of a unit tracked by [`UnitChange`]. Named like the accessor of the unit
+/// the value is read from, `Orbits` and `Vectors` are the number of entries.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::AsRefStr,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UnitField {
+    ConfiguredPosition,
+    Orbits,
+    Hull,
+    HullMaximum,
+    Shield,
+    ShieldActive,
+    Energy,
+    Ions,
+    Neutrinos,
+    EffectiveStructuralLoad,
+    Amount,
+    Ticks,
+    Load,
+    Damage,
+    DamagePhase,
+    Heat,
+    Drain,
+    GravityWellRadius,
+    GravityWellForce,
+    Metal,
+    Carbon,
+    Hydrogen,
+    Silicon,
+    Mode,
+    Flow,
+    RadialForce,
+    TangentialForce,
+    Hue,
+    SpawnChancePerTick,
+    MinAnnouncementTicks,
+    MaxAnnouncementTicks,
+    MinActiveTicks,
+    MaxActiveTicks,
+    MinWhirlRadius,
+    MaxWhirlRadius,
+    MinWhirlSpeed,
+    MaxWhirlSpeed,
+    MinWhirlGravity,
+    MaxWhirlGravity,
+    Message,
+    TargetCluster,
+    TargetLeft,
+    TargetTop,
+    TargetRight,
+    TargetBottom,
+    Closed,
+    RestoreRemainingTicks,
+    Switched,
+    CooldownRemainingTicks,
+    Active,
+    GraceTicks,
+    Domination,
+    ScoreCountdown,
+    SequenceNumber,
+    Vectors,
+    TargetClusterId,
+    TargetUnitName,
+    TargetUnitKind,
+}
+
+/// A changed kind specific field of a [`UnitChange`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnitFieldChange {
+    pub field: UnitField,
+    pub old: UnitValue,
+    pub new: UnitValue,
+}
+
+/// The value of a kind specific field of a unit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum UnitValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f32),
+    Vector(Vector),
+    Text(Arc<String>),
+    Cluster(ClusterId),
+    UnitKind(UnitKind),
+    CurrentFieldMode(CurrentFieldMode),
+    RailgunDirection(RailgunDirection),
+    /// The field has no value, like the restore timer of a gate which is not going to restore.
+    None,
+}
+
+impl UnitValue {
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Integers are converted.
+    #[inline]
+    pub fn as_float(&self) -> Option<f32> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Integer(value) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_vector(&self) -> Option<Vector> {
+        match self {
+            Self::Vector(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_cluster(&self) -> Option<ClusterId> {
+        match self {
+            Self::Cluster(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_unit_kind(&self) -> Option<UnitKind> {
+        match self {
+            Self::UnitKind(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Display for UnitValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => Display::fmt(value, f),
+            Self::Integer(value) => Display::fmt(value, f),
+            Self::Float(value) => Display::fmt(value, f),
+            Self::Vector(value) => Display::fmt(value, f),
+            Self::Text(value) => write!(f, "{value:?}"),
+            Self::Cluster(value) => Display::fmt(&value.0, f),
+            Self::UnitKind(value) => Display::fmt(value, f),
+            Self::CurrentFieldMode(value) => Display::fmt(value, f),
+            Self::RailgunDirection(value) => Display::fmt(value, f),
+            Self::None => f.write_str("none"),
+        }
+    }
+}
+
+macro_rules! unit_value_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(impl From<$ty> for UnitValue {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                Self::$variant(value)
+            }
+        })*
+    };
+}
+
+unit_value_from!(
+    bool => Bool,
+    f32 => Float,
+    Vector => Vector,
+    Arc<String> => Text,
+    ClusterId => Cluster,
+    UnitKind => UnitKind,
+    CurrentFieldMode => CurrentFieldMode,
+    RailgunDirection => RailgunDirection,
+);
+
+macro_rules! unit_value_from_integer {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for UnitValue {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                Self::Integer(i64::from(value))
+            }
+        })*
+    };
+}
+
+unit_value_from_integer!(u8, u16, i32, u32);
+
+impl From<String> for UnitValue {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::Text(Arc::new(value))
+    }
+}
+
+impl<T: Into<UnitValue>> From<Option<T>> for UnitValue {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::None)
+    }
+}
+
+/// The values of a unit an update can change, captured before and after the update to build the
+/// [`UnitChange`]. Capturing does not allocate: the values are stored inline and texts, like the
+/// target unit name of a [`crate::unit::SpaceJellyFishSlime`], share the `Arc` of the unit.
+#[derive(Debug)]
+pub(crate) struct UnitFieldValues {
+    position: Vector,
+    movement: Vector,
+    angle: f32,
+    team: Option<TeamId>,
+    fields: Fields,
+}
+
+impl UnitFieldValues {
+    /// The values a movement update can change.
+    pub(crate) fn movement(unit: &dyn Unit) -> Self {
+        let mut fields = Fields::default();
+
+        if let Some(projectile) = unit.as_projectile() {
+            fields.push(UnitField::Ticks, projectile.ticks());
+        }
+        if let Some(explosion) = unit.as_explosion() {
+            fields.push(UnitField::DamagePhase, explosion.is_damage_phase());
+        }
+
+        Self::new(unit, fields)
+    }
+
+    /// The values a state update can change.
+    pub(crate) fn state(unit: &dyn Unit) -> Self {
+        let mut fields = Fields::default();
+
+        if let Some(steady) = unit.as_steady_unit() {
+            fields.push(UnitField::ConfiguredPosition, steady.configured_position());
+            fields.push(UnitField::Orbits, steady.orbiting_list().len() as u32);
+        }
+        if let Some(player_unit) = unit.as_player_unit() {
+            fields.push(UnitField::Hull, player_unit.hull().current());
+            fields.push(UnitField::Shield, player_unit.shield().current());
+            fields.push(UnitField::ShieldActive, player_unit.shield().active());
+            fields.push(UnitField::Energy, player_unit.energy_battery().current());
+            fields.push(UnitField::Ions, player_unit.ion_battery().current());
+            fields.push(
+                UnitField::Neutrinos,
+                player_unit.neutrino_battery().current(),
+            );
+            fields.push(
+                UnitField::EffectiveStructuralLoad,
+                player_unit.effective_structural_load(),
+            );
+        }
+        if let Some(npc) = unit.as_npc_unit() {
+            fields.push(UnitField::Hull, npc.hull());
+            fields.push(UnitField::HullMaximum, npc.hull_maximum());
+        }
+        if let Some(npc) = unit.as_mobile_npc_unit() {
+            fields.push(UnitField::Hull, npc.hull());
+            fields.push(UnitField::HullMaximum, npc.hull_max());
+        }
+        if let Some(power_up) = unit.as_power_up() {
+            fields.push(UnitField::Amount, power_up.amount());
+        }
+        if let Some(projectile) = unit.as_projectile() {
+            fields.push(UnitField::Load, projectile.load());
+            fields.push(UnitField::Damage, projectile.damage());
+        }
+
+        if let Some(sun) = unit.as_sun() {
+            fields.push(UnitField::Energy, sun.energy());
+            fields.push(UnitField::Ions, sun.ions());
+            fields.push(UnitField::Neutrinos, sun.neutrinos());
+            fields.push(UnitField::Heat, sun.heat());
+            fields.push(UnitField::Drain, sun.drain());
+        } else if let Some(black_hole) = unit.as_black_hole() {
+            fields.push(
+                UnitField::GravityWellRadius,
+                black_hole.gravity_well_radius(),
+            );
+            fields.push(UnitField::GravityWellForce, black_hole.gravity_well_force());
+        } else if let Some(planet) = unit.as_planet() {
+            fields.resources(
+                planet.metal(),
+                planet.carbon(),
+                planet.hydrogen(),
+                planet.silicon(),
+            );
+        } else if let Some(moon) = unit.as_moon() {
+            fields.resources(moon.metal(), moon.carbon(), moon.hydrogen(), moon.silicon());
+        } else if let Some(meteoroid) = unit.as_meteoroid() {
+            fields.resources(
+                meteoroid.metal(),
+                meteoroid.carbon(),
+                meteoroid.hydrogen(),
+                meteoroid.silicon(),
+            );
+        } else if let Some(freighter) = unit.as_ai_freighter() {
+            fields.resources(
+                freighter.metal(),
+                freighter.carbon(),
+                freighter.hydrogen(),
+                freighter.silicon(),
+            );
+        } else if let Some(current_field) = unit.as_current_field() {
+            fields.push(UnitField::Mode, current_field.mode());
+            fields.push(UnitField::Flow, current_field.flow());
+            fields.push(UnitField::RadialForce, current_field.radial_force());
+            fields.push(UnitField::TangentialForce, current_field.tangential_force());
+        } else if let Some(nebula) = unit.as_nebula() {
+            fields.push(UnitField::Hue, nebula.hue());
+        } else if let Some(storm) = unit.as_storm() {
+            fields.push(UnitField::SpawnChancePerTick, storm.spawn_chance_per_tick());
+            fields.push(
+                UnitField::MinAnnouncementTicks,
+                storm.min_announcement_ticks(),
+            );
+            fields.push(
+                UnitField::MaxAnnouncementTicks,
+                storm.max_announcement_ticks(),
+            );
+            fields.push(UnitField::MinActiveTicks, storm.min_active_ticks());
+            fields.push(UnitField::MaxActiveTicks, storm.max_active_ticks());
+            fields.push(UnitField::MinWhirlRadius, storm.min_whirl_radius());
+            fields.push(UnitField::MaxWhirlRadius, storm.max_whirl_radius());
+            fields.push(UnitField::MinWhirlSpeed, storm.min_whirl_speed());
+            fields.push(UnitField::MaxWhirlSpeed, storm.max_whirl_speed());
+            fields.push(UnitField::MinWhirlGravity, storm.min_whirl_gravity());
+            fields.push(UnitField::MaxWhirlGravity, storm.max_whirl_gravity());
+            fields.push(UnitField::Damage, storm.damage());
+        } else if let Some(whirl) = unit.as_storm_active_whirl() {
+            fields.push(UnitField::Damage, whirl.damage());
+        } else if let Some(buoy) = unit.as_buoy() {
+            fields.push(UnitField::Message, buoy.message().as_ref().map(Arc::clone));
+        } else if let Some(worm_hole) = unit.as_worm_hole() {
+            fields.push(
+                UnitField::TargetCluster,
+                worm_hole
+                    .target_cluster()
+                    .upgrade()
+                    .map(|cluster| cluster.id()),
+            );
+            fields.push(UnitField::TargetLeft, worm_hole.target_left());
+            fields.push(UnitField::TargetTop, worm_hole.target_top());
+            fields.push(UnitField::TargetRight, worm_hole.target_right());
+            fields.push(UnitField::TargetBottom, worm_hole.target_bottom());
+        } else if let Some(gate) = unit.as_gate() {
+            fields.push(UnitField::Closed, gate.closed());
+            fields.push(
+                UnitField::RestoreRemainingTicks,
+                gate.restore_remaining_ticks(),
+            );
+        } else if let Some(switch) = unit.as_switch() {
+            fields.push(UnitField::Switched, switch.switched());
+            fields.push(
+                UnitField::CooldownRemainingTicks,
+                switch.cooldown_remaining_ticks(),
+            );
+        } else if let Some(flag) = unit.as_flag() {
+            fields.push(UnitField::Active, flag.active());
+            fields.push(UnitField::GraceTicks, flag.grace_ticks());
+        } else if let Some(domination_point) = unit.as_domination_point() {
+            fields.push(UnitField::Domination, domination_point.domination());
+            fields.push(
+                UnitField::ScoreCountdown,
+                domination_point.score_countdown(),
+            );
+        } else if let Some(mission_target) = unit.as_mission_target() {
+            fields.push(UnitField::SequenceNumber, mission_target.sequence_number());
+            fields.push(UnitField::Vectors, mission_target.vectors().len() as u32);
+        } else if let Some(slime) = unit.as_space_jelly_fish_slime() {
+            fields.push(UnitField::TargetClusterId, slime.target_cluster_id());
+            fields.push(UnitField::TargetUnitName, slime.target_unit_name());
+            fields.push(UnitField::TargetUnitKind, slime.target_unit_kind());
+        }
+
+        Self::new(unit, fields)
+    }
+
+    #[inline]
+    fn new(unit: &dyn Unit, fields: Fields) -> Self {
+        Self {
+            position: unit.position(),
+            movement: unit.movement(),
+            angle: unit.angle(),
+            team: unit.team().upgrade().map(|team| team.id()),
+            fields,
+        }
+    }
+
+    /// The changes from this state to the `new` state of the same unit, captured the same way.
+    pub(crate) fn changes_to(self, new: UnitFieldValues) -> UnitChange {
+        UnitChange {
+            position: Self::change(self.position, new.position),
+            movement: Self::change(self.movement, new.movement),
+            angle: Self::change(self.angle, new.angle),
+            team: Self::change(self.team, new.team),
+            fields: self
+                .fields
+                .into_values()
+                .zip(new.fields.into_values())
+                .filter(|((_, old), (_, new))| old != new)
+                .map(|((field, old), (_, new))| UnitFieldChange { field, old, new })
+                .collect(),
+        }
+    }
+
+    #[inline]
+    fn change<T: PartialEq>(old: T, new: T) -> Option<Change<T>> {
+        (old != new).then(|| Change::new(old, new))
+    }
+}
+
+/// The kind specific values of a [`UnitFieldValues`], stored inline.
+#[derive(Debug, Default)]
+struct Fields {
+    len: usize,
+    values: [Option<(UnitField, UnitValue)>; Fields::CAPACITY],
+}
+
+impl Fields {
+    /// Enough for the kind with the most fields, a [`crate::unit::Storm`].
+    const CAPACITY: usize = 16;
+
+    #[inline]
+    fn push(&mut self, field: UnitField, value: impl Into<UnitValue>) {
+        debug_assert!(self.len < Self::CAPACITY, "too many fields at {field}");
+        if let Some(slot) = self.values.get_mut(self.len) {
+            *slot = Some((field, value.into()));
+            self.len += 1;
+        }
+    }
+
+    fn resources(&mut self, metal: f32, carbon: f32, hydrogen: f32, silicon: f32) {
+        self.push(UnitField::Metal, metal);
+        self.push(UnitField::Carbon, carbon);
+        self.push(UnitField::Hydrogen, hydrogen);
+        self.push(UnitField::Silicon, silicon);
+    }
+
+    #[inline]
+    fn into_values(self) -> impl Iterator<Item = (UnitField, UnitValue)> {
+        self.values.into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(
+        field: UnitField,
+        old: impl Into<UnitValue>,
+        new: impl Into<UnitValue>,
+    ) -> UnitFieldChange {
+        UnitFieldChange {
+            field,
+            old: old.into(),
+            new: new.into(),
+        }
+    }
+
+    #[test]
+    fn merged_keeps_the_first_old_and_the_last_new_value() {
+        let first = UnitChange {
+            angle: Some(Change::new(10.0, 20.0)),
+            fields: vec![
+                field(UnitField::Closed, false, true),
+                field(UnitField::Hull, 50.0, 40.0),
+            ],
+            ..UnitChange::default()
+        };
+        let later = UnitChange {
+            angle: Some(Change::new(20.0, 10.0)),
+            position: Some(Change::new(Vector::default(), Vector::new(1.0, 0.0))),
+            fields: vec![
+                field(UnitField::Closed, true, false),
+                field(UnitField::Hull, 40.0, 30.0),
+                field(UnitField::Shield, 5.0, 0.0),
+            ],
+            ..UnitChange::default()
+        };
+
+        let merged = first.merged(&later);
+
+        assert_eq!(merged.angle, None);
+        assert_eq!(merged.position, later.position);
+        assert!(!merged.changed(UnitField::Closed));
+        assert_eq!(merged.float(UnitField::Hull), Some(Change::new(50.0, 30.0)));
+        assert_eq!(merged.float(UnitField::Shield), Some(Change::new(5.0, 0.0)));
+    }
+}
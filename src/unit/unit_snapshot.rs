@@ -0,0 +1,747 @@
+use crate::galaxy_hierarchy::{ClusterId, ControllableInfoId, PlayerId, TeamId};
+use crate::unit::{
+    ArmorSubsystemInfo, BatterySubsystemInfo, CargoSubsystemInfo, ClassicRailgunSubsystemInfo,
+    ClassicShipEngineSubsystemInfo, ClassicShipPlayerUnit, CurrentFieldMode,
+    DynamicInterceptorFabricatorSubsystemInfo, DynamicInterceptorLauncherSubsystemInfo,
+    DynamicInterceptorMagazineSubsystemInfo, DynamicScannerSubsystemInfo,
+    DynamicShotFabricatorSubsystemInfo, DynamicShotLauncherSubsystemInfo,
+    DynamicShotMagazineSubsystemInfo, EnergyCellSubsystemInfo, HullSubsystemInfo,
+    JumpDriveSubsystemInfo, MeteoroidType, Mobility, ModernRailgunSubsystemInfo,
+    ModernShipEngineSubsystemInfo, ModernShipPlayerUnit, MoonType, NebulaCollectorSubsystemInfo,
+    Orbit, PlanetType, PlayerUnit, RepairSubsystemInfo, ResourceMinerSubsystemInfo,
+    ShieldSubsystemInfo, StaticInterceptorMagazineSubsystemInfo, StaticShotMagazineSubsystemInfo,
+    SwitchMode, Unit, UnitKind,
+};
+use crate::Vector;
+use serde::{Deserialize, Serialize};
+
+/// Owned, serializable copy of a [`Unit`] with the common fields, the fields of its unit
+/// hierarchy and all fields of its kind. Any [`Unit`], and therefore any `Arc<dyn Unit>`,
+/// serializes as its snapshot, and the snapshot is how it deserializes again.
+///
+/// Serialized, the [`UnitKind`] is stored in the `kind` field next to the fields of the kind.
+/// Handles to players, teams and clusters are stored as their ids. Only available with the
+/// `unit-serde` feature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitSnapshot {
+    pub name: String,
+    pub cluster: ClusterId,
+    pub position: Vector,
+    pub movement: Vector,
+    pub angle: f32,
+    pub radius: f32,
+    pub gravity: f32,
+    pub speed_limit: f32,
+    pub mobility: Mobility,
+    pub is_masking: bool,
+    pub is_solid: bool,
+    pub can_be_edited: bool,
+    pub full_state_known: bool,
+    /// `None` for units without a team.
+    pub team: Option<TeamId>,
+    /// Set for steady units.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steady: Option<SteadySnapshot>,
+    /// Set for mobile units.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angular_velocity: Option<f32>,
+    /// Set for power-ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f32>,
+    /// Set for npc units, the current and the maximum hull.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub npc_hull: Option<(f32, f32)>,
+    /// Set for player units.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player_unit: Option<Box<PlayerUnitSnapshot>>,
+    /// Set for projectiles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projectile: Option<ProjectileSnapshot>,
+    /// Set for explosions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explosion: Option<ExplosionSnapshot>,
+    #[serde(flatten)]
+    pub details: UnitDetails,
+}
+
+impl UnitSnapshot {
+    #[inline]
+    pub fn kind(&self) -> UnitKind {
+        self.details.kind()
+    }
+}
+
+impl From<&dyn Unit> for UnitSnapshot {
+    fn from(unit: &dyn Unit) -> Self {
+        Self {
+            name: unit.name().to_string(),
+            cluster: unit.cluster().id(),
+            position: unit.position(),
+            movement: unit.movement(),
+            angle: unit.angle(),
+            radius: unit.radius(),
+            gravity: unit.gravity(),
+            speed_limit: unit.speed_limit(),
+            mobility: unit.mobility(),
+            is_masking: unit.is_masking(),
+            is_solid: unit.is_solid(),
+            can_be_edited: unit.can_be_edited(),
+            full_state_known: unit.full_state_known(),
+            team: unit.team().upgrade().map(|team| team.id()),
+            steady: unit.as_steady_unit().map(|steady| SteadySnapshot {
+                configured_position: steady.configured_position(),
+                orbits: steady.orbiting_list().to_vec(),
+            }),
+            angular_velocity: unit
+                .as_mobile_unit()
+                .map(|mobile| mobile.angular_velocity()),
+            amount: unit.as_power_up().map(|power_up| power_up.amount()),
+            npc_hull: unit
+                .as_npc_unit()
+                .map(|npc| (npc.hull(), npc.hull_maximum()))
+                .or_else(|| {
+                    unit.as_mobile_npc_unit()
+                        .map(|npc| (npc.hull(), npc.hull_max()))
+                }),
+            player_unit: unit
+                .as_player_unit()
+                .map(|player_unit| Box::new(PlayerUnitSnapshot::from(player_unit))),
+            projectile: unit.as_projectile().map(|projectile| ProjectileSnapshot {
+                player: projectile.player().upgrade().map(|player| player.id()),
+                controllable_info: projectile
+                    .controllable_info()
+                    .upgrade()
+                    .map(|info| info.id()),
+                ticks: projectile.ticks(),
+                load: projectile.load(),
+                damage: projectile.damage(),
+            }),
+            explosion: unit.as_explosion().map(|explosion| ExplosionSnapshot {
+                player: explosion.player().upgrade().map(|player| player.id()),
+                controllable_info: explosion
+                    .controllable_info()
+                    .upgrade()
+                    .map(|info| info.id()),
+                damage_phase: explosion.is_damage_phase(),
+                shock_wave_phase: explosion.is_shock_wave_phase(),
+                damage: explosion.damage(),
+            }),
+            details: UnitDetails::from(unit),
+        }
+    }
+}
+
+impl Serialize for dyn Unit {
+    /// Serializes the [`UnitSnapshot`] of the unit.
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        UnitSnapshot::from(self).serialize(serializer)
+    }
+}
+
+/// See [`crate::unit::SteadyUnit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SteadySnapshot {
+    pub configured_position: Vector,
+    pub orbits: Vec<Orbit>,
+}
+
+/// See [`PlayerUnit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerUnitSnapshot {
+    pub player: PlayerId,
+    pub controllable_info: ControllableInfoId,
+    pub effective_structural_load: f32,
+    pub energy_battery: BatterySubsystemInfo,
+    pub ion_battery: BatterySubsystemInfo,
+    pub neutrino_battery: BatterySubsystemInfo,
+    pub energy_cell: EnergyCellSubsystemInfo,
+    pub ion_cell: EnergyCellSubsystemInfo,
+    pub neutrino_cell: EnergyCellSubsystemInfo,
+    pub hull: HullSubsystemInfo,
+    pub shield: ShieldSubsystemInfo,
+    pub armor: ArmorSubsystemInfo,
+    pub repair: RepairSubsystemInfo,
+    pub cargo: CargoSubsystemInfo,
+    pub resource_miner: ResourceMinerSubsystemInfo,
+}
+
+impl From<&dyn PlayerUnit> for PlayerUnitSnapshot {
+    fn from(unit: &dyn PlayerUnit) -> Self {
+        Self {
+            player: unit.player().id(),
+            controllable_info: unit.controllable_info().id(),
+            effective_structural_load: unit.effective_structural_load(),
+            energy_battery: unit.energy_battery().clone(),
+            ion_battery: unit.ion_battery().clone(),
+            neutrino_battery: unit.neutrino_battery().clone(),
+            energy_cell: unit.energy_cell().clone(),
+            ion_cell: unit.ion_cell().clone(),
+            neutrino_cell: unit.neutrino_cell().clone(),
+            hull: unit.hull().clone(),
+            shield: unit.shield().clone(),
+            armor: unit.armor().clone(),
+            repair: unit.repair().clone(),
+            cargo: unit.cargo().clone(),
+            resource_miner: unit.resource_miner().clone(),
+        }
+    }
+}
+
+/// See [`crate::unit::Projectile`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectileSnapshot {
+    /// `None` if no player-owned source is known.
+    pub player: Option<PlayerId>,
+    /// `None` if no player-owned source is known.
+    pub controllable_info: Option<ControllableInfoId>,
+    pub ticks: u16,
+    pub load: f32,
+    pub damage: f32,
+}
+
+/// See [`crate::unit::Explosion`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplosionSnapshot {
+    /// `None` if no player-owned source is known.
+    pub player: Option<PlayerId>,
+    /// `None` if no player-owned source is known.
+    pub controllable_info: Option<ControllableInfoId>,
+    pub damage_phase: bool,
+    pub shock_wave_phase: bool,
+    pub damage: f32,
+}
+
+/// The fields of the concrete kind of a [`UnitSnapshot`], tagged with the [`UnitKind`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum UnitDetails {
+    Sun {
+        energy: f32,
+        ions: f32,
+        neutrinos: f32,
+        heat: f32,
+        drain: f32,
+    },
+    BlackHole {
+        gravity_well_radius: f32,
+        gravity_well_force: f32,
+    },
+    CurrentField {
+        mode: CurrentFieldMode,
+        flow: Vector,
+        radial_force: f32,
+        tangential_force: f32,
+    },
+    Nebula {
+        hue: f32,
+    },
+    Storm {
+        spawn_chance_per_tick: f32,
+        min_announcement_ticks: u16,
+        max_announcement_ticks: u16,
+        min_active_ticks: u16,
+        max_active_ticks: u16,
+        min_whirl_radius: f32,
+        max_whirl_radius: f32,
+        min_whirl_speed: f32,
+        max_whirl_speed: f32,
+        min_whirl_gravity: f32,
+        max_whirl_gravity: f32,
+        damage: f32,
+    },
+    StormCommencingWhirl,
+    StormActiveWhirl {
+        damage: f32,
+    },
+    Planet {
+        planet_type: PlanetType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    Moon {
+        moon_type: MoonType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    Meteoroid {
+        meteoroid_type: MeteoroidType,
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    Buoy {
+        message: Option<String>,
+    },
+    WormHole {
+        /// `None` if the target cluster is unknown.
+        target_cluster: Option<ClusterId>,
+        target_left: f32,
+        target_top: f32,
+        target_right: f32,
+        target_bottom: f32,
+    },
+    MissionTarget {
+        sequence_number: u16,
+        vectors: Vec<Vector>,
+    },
+    Flag {
+        grace_ticks: u16,
+        active: bool,
+    },
+    DominationPoint {
+        domination_radius: f32,
+        domination: i32,
+        score_countdown: i32,
+    },
+    EnergyChargePowerUp,
+    IonChargePowerUp,
+    NeutrinoChargePowerUp,
+    MetalCargoPowerUp,
+    CarbonCargoPowerUp,
+    HydrogenCargoPowerUp,
+    SiliconCargoPowerUp,
+    ShieldChargePowerUp,
+    HullRepairPowerUp,
+    ShotChargePowerUp,
+    Switch {
+        link_id: u16,
+        range: f32,
+        cooldown_ticks: u16,
+        cooldown_remaining_ticks: u16,
+        mode: SwitchMode,
+        switched: bool,
+    },
+    Gate {
+        linked_id: u16,
+        default_closed: bool,
+        restore_ticks: Option<u16>,
+        closed: bool,
+        restore_remaining_ticks: Option<u16>,
+    },
+    SpaceJellyFish,
+    SpaceJellyFishSlime {
+        target_cluster_id: u8,
+        target_unit_name: String,
+        target_unit_kind: Option<UnitKind>,
+    },
+    AiBase,
+    AiTurret,
+    AiFreighter {
+        metal: f32,
+        carbon: f32,
+        hydrogen: f32,
+        silicon: f32,
+    },
+    AiShip,
+    AiProbe,
+    Shot,
+    Interceptor,
+    Rail,
+    ClassicShipPlayerUnit(Box<ClassicShipSnapshot>),
+    ModernShipPlayerUnit(Box<ModernShipSnapshot>),
+    InterceptorExplosion,
+    Explosion,
+    Unknown {
+        id: u8,
+    },
+}
+
+impl UnitDetails {
+    pub fn kind(&self) -> UnitKind {
+        match self {
+            Self::Sun { .. } => UnitKind::Sun,
+            Self::BlackHole { .. } => UnitKind::BlackHole,
+            Self::CurrentField { .. } => UnitKind::CurrentField,
+            Self::Nebula { .. } => UnitKind::Nebula,
+            Self::Storm { .. } => UnitKind::Storm,
+            Self::StormCommencingWhirl => UnitKind::StormCommencingWhirl,
+            Self::StormActiveWhirl { .. } => UnitKind::StormActiveWhirl,
+            Self::Planet { .. } => UnitKind::Planet,
+            Self::Moon { .. } => UnitKind::Moon,
+            Self::Meteoroid { .. } => UnitKind::Meteoroid,
+            Self::Buoy { .. } => UnitKind::Buoy,
+            Self::WormHole { .. } => UnitKind::WormHole,
+            Self::MissionTarget { .. } => UnitKind::MissionTarget,
+            Self::Flag { .. } => UnitKind::Flag,
+            Self::DominationPoint { .. } => UnitKind::DominationPoint,
+            Self::EnergyChargePowerUp => UnitKind::EnergyChargePowerUp,
+            Self::IonChargePowerUp => UnitKind::IonChargePowerUp,
+            Self::NeutrinoChargePowerUp => UnitKind::NeutrinoChargePowerUp,
+            Self::MetalCargoPowerUp => UnitKind::MetalCargoPowerUp,
+            Self::CarbonCargoPowerUp => UnitKind::CarbonCargoPowerUp,
+            Self::HydrogenCargoPowerUp => UnitKind::HydrogenCargoPowerUp,
+            Self::SiliconCargoPowerUp => UnitKind::SiliconCargoPowerUp,
+            Self::ShieldChargePowerUp => UnitKind::ShieldChargePowerUp,
+            Self::HullRepairPowerUp => UnitKind::HullRepairPowerUp,
+            Self::ShotChargePowerUp => UnitKind::ShotChargePowerUp,
+            Self::Switch { .. } => UnitKind::Switch,
+            Self::Gate { .. } => UnitKind::Gate,
+            Self::SpaceJellyFish => UnitKind::SpaceJellyFish,
+            Self::SpaceJellyFishSlime { .. } => UnitKind::SpaceJellyFishSlime,
+            Self::AiBase => UnitKind::AiBase,
+            Self::AiTurret => UnitKind::AiTurret,
+            Self::AiFreighter { .. } => UnitKind::AiFreighter,
+            Self::AiShip => UnitKind::AiShip,
+            Self::AiProbe => UnitKind::AiProbe,
+            Self::Shot => UnitKind::Shot,
+            Self::Interceptor => UnitKind::Interceptor,
+            Self::Rail => UnitKind::Rail,
+            Self::ClassicShipPlayerUnit(_) => UnitKind::ClassicShipPlayerUnit,
+            Self::ModernShipPlayerUnit(_) => UnitKind::ModernShipPlayerUnit,
+            Self::InterceptorExplosion => UnitKind::InterceptorExplosion,
+            Self::Explosion => UnitKind::Explosion,
+            Self::Unknown { id } => UnitKind::Unknown(*id),
+        }
+    }
+}
+
+impl From<&dyn Unit> for UnitDetails {
+    fn from(unit: &dyn Unit) -> Self {
+        match unit.kind() {
+            UnitKind::Sun => unit.as_sun().map(|sun| Self::Sun {
+                energy: sun.energy(),
+                ions: sun.ions(),
+                neutrinos: sun.neutrinos(),
+                heat: sun.heat(),
+                drain: sun.drain(),
+            }),
+            UnitKind::BlackHole => unit.as_black_hole().map(|black_hole| Self::BlackHole {
+                gravity_well_radius: black_hole.gravity_well_radius(),
+                gravity_well_force: black_hole.gravity_well_force(),
+            }),
+            UnitKind::CurrentField => unit.as_current_field().map(|field| Self::CurrentField {
+                mode: field.mode(),
+                flow: field.flow(),
+                radial_force: field.radial_force(),
+                tangential_force: field.tangential_force(),
+            }),
+            UnitKind::Nebula => unit
+                .as_nebula()
+                .map(|nebula| Self::Nebula { hue: nebula.hue() }),
+            UnitKind::Storm => unit.as_storm().map(|storm| Self::Storm {
+                spawn_chance_per_tick: storm.spawn_chance_per_tick(),
+                min_announcement_ticks: storm.min_announcement_ticks(),
+                max_announcement_ticks: storm.max_announcement_ticks(),
+                min_active_ticks: storm.min_active_ticks(),
+                max_active_ticks: storm.max_active_ticks(),
+                min_whirl_radius: storm.min_whirl_radius(),
+                max_whirl_radius: storm.max_whirl_radius(),
+                min_whirl_speed: storm.min_whirl_speed(),
+                max_whirl_speed: storm.max_whirl_speed(),
+                min_whirl_gravity: storm.min_whirl_gravity(),
+                max_whirl_gravity: storm.max_whirl_gravity(),
+                damage: storm.damage(),
+            }),
+            UnitKind::StormCommencingWhirl => Some(Self::StormCommencingWhirl),
+            UnitKind::StormActiveWhirl => {
+                unit.as_storm_active_whirl()
+                    .map(|whirl| Self::StormActiveWhirl {
+                        damage: whirl.damage(),
+                    })
+            }
+            UnitKind::Planet => unit.as_planet().map(|planet| Self::Planet {
+                planet_type: planet.r#type(),
+                metal: planet.metal(),
+                carbon: planet.carbon(),
+                hydrogen: planet.hydrogen(),
+                silicon: planet.silicon(),
+            }),
+            UnitKind::Moon => unit.as_moon().map(|moon| Self::Moon {
+                moon_type: moon.r#type(),
+                metal: moon.metal(),
+                carbon: moon.carbon(),
+                hydrogen: moon.hydrogen(),
+                silicon: moon.silicon(),
+            }),
+            UnitKind::Meteoroid => unit.as_meteoroid().map(|meteoroid| Self::Meteoroid {
+                meteoroid_type: meteoroid.r#type(),
+                metal: meteoroid.metal(),
+                carbon: meteoroid.carbon(),
+                hydrogen: meteoroid.hydrogen(),
+                silicon: meteoroid.silicon(),
+            }),
+            UnitKind::Buoy => unit.as_buoy().map(|buoy| Self::Buoy {
+                message: buoy.message().as_ref().map(|message| message.to_string()),
+            }),
+            UnitKind::WormHole => unit.as_worm_hole().map(|worm_hole| Self::WormHole {
+                target_cluster: worm_hole
+                    .target_cluster()
+                    .upgrade()
+                    .map(|cluster| cluster.id()),
+                target_left: worm_hole.target_left(),
+                target_top: worm_hole.target_top(),
+                target_right: worm_hole.target_right(),
+                target_bottom: worm_hole.target_bottom(),
+            }),
+            UnitKind::MissionTarget => unit.as_mission_target().map(|target| Self::MissionTarget {
+                sequence_number: target.sequence_number(),
+                vectors: target.vectors().to_vec(),
+            }),
+            UnitKind::Flag => unit.as_flag().map(|flag| Self::Flag {
+                grace_ticks: flag.grace_ticks(),
+                active: flag.active(),
+            }),
+            UnitKind::DominationPoint => {
+                unit.as_domination_point()
+                    .map(|point| Self::DominationPoint {
+                        domination_radius: point.domination_radius(),
+                        domination: point.domination(),
+                        score_countdown: point.score_countdown(),
+                    })
+            }
+            UnitKind::EnergyChargePowerUp => Some(Self::EnergyChargePowerUp),
+            UnitKind::IonChargePowerUp => Some(Self::IonChargePowerUp),
+            UnitKind::NeutrinoChargePowerUp => Some(Self::NeutrinoChargePowerUp),
+            UnitKind::MetalCargoPowerUp => Some(Self::MetalCargoPowerUp),
+            UnitKind::CarbonCargoPowerUp => Some(Self::CarbonCargoPowerUp),
+            UnitKind::HydrogenCargoPowerUp => Some(Self::HydrogenCargoPowerUp),
+            UnitKind::SiliconCargoPowerUp => Some(Self::SiliconCargoPowerUp),
+            UnitKind::ShieldChargePowerUp => Some(Self::ShieldChargePowerUp),
+            UnitKind::HullRepairPowerUp => Some(Self::HullRepairPowerUp),
+            UnitKind::ShotChargePowerUp => Some(Self::ShotChargePowerUp),
+            UnitKind::Switch => unit.as_switch().map(|switch| Self::Switch {
+                link_id: switch.link_id(),
+                range: switch.range(),
+                cooldown_ticks: switch.cooldown_ticks(),
+                cooldown_remaining_ticks: switch.cooldown_remaining_ticks(),
+                mode: switch.mode(),
+                switched: switch.switched(),
+            }),
+            UnitKind::Gate => unit.as_gate().map(|gate| Self::Gate {
+                linked_id: gate.linked_it(),
+                default_closed: gate.default_closed(),
+                restore_ticks: gate.restore_ticks(),
+                closed: gate.closed(),
+                restore_remaining_ticks: gate.restore_remaining_ticks(),
+            }),
+            UnitKind::SpaceJellyFish => Some(Self::SpaceJellyFish),
+            UnitKind::SpaceJellyFishSlime => {
+                unit.as_space_jelly_fish_slime()
+                    .map(|slime| Self::SpaceJellyFishSlime {
+                        target_cluster_id: slime.target_cluster_id(),
+                        target_unit_name: slime.target_unit_name().to_string(),
+                        target_unit_kind: slime.target_unit_kind(),
+                    })
+            }
+            UnitKind::AiBase => Some(Self::AiBase),
+            UnitKind::AiTurret => Some(Self::AiTurret),
+            UnitKind::AiFreighter => unit.as_ai_freighter().map(|freighter| Self::AiFreighter {
+                metal: freighter.metal(),
+                carbon: freighter.carbon(),
+                hydrogen: freighter.hydrogen(),
+                silicon: freighter.silicon(),
+            }),
+            UnitKind::AiShip => Some(Self::AiShip),
+            UnitKind::AiProbe => Some(Self::AiProbe),
+            UnitKind::Shot => Some(Self::Shot),
+            UnitKind::Interceptor => Some(Self::Interceptor),
+            UnitKind::Rail => Some(Self::Rail),
+            UnitKind::ClassicShipPlayerUnit => unit
+                .as_classic_ship()
+                .map(|ship| Self::ClassicShipPlayerUnit(Box::new(ClassicShipSnapshot::from(ship)))),
+            UnitKind::ModernShipPlayerUnit => unit
+                .as_modern_ship()
+                .map(|ship| Self::ModernShipPlayerUnit(Box::new(ModernShipSnapshot::from(ship)))),
+            UnitKind::InterceptorExplosion => Some(Self::InterceptorExplosion),
+            UnitKind::Explosion => Some(Self::Explosion),
+            UnitKind::Unknown(id) => Some(Self::Unknown { id }),
+        }
+        .unwrap_or_else(|| {
+            debug_assert!(false, "{:?} is not of its kind.", unit.name());
+            Self::Unknown {
+                id: u8::from(unit.kind()),
+            }
+        })
+    }
+}
+
+/// The subsystems of a [`ClassicShipPlayerUnit`] in a [`UnitSnapshot`], next to the ones in
+/// [`PlayerUnitSnapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassicShipSnapshot {
+    pub engine: ClassicShipEngineSubsystemInfo,
+    pub nebula_collector: NebulaCollectorSubsystemInfo,
+    pub shot_launcher: DynamicShotLauncherSubsystemInfo,
+    pub shot_magazine: DynamicShotMagazineSubsystemInfo,
+    pub shot_fabricator: DynamicShotFabricatorSubsystemInfo,
+    pub interceptor_launcher: DynamicInterceptorLauncherSubsystemInfo,
+    pub interceptor_magazine: DynamicInterceptorMagazineSubsystemInfo,
+    pub interceptor_fabricator: DynamicInterceptorFabricatorSubsystemInfo,
+    pub railgun: ClassicRailgunSubsystemInfo,
+    pub main_scanner: DynamicScannerSubsystemInfo,
+    pub secondary_scanner: DynamicScannerSubsystemInfo,
+    pub jump_drive: JumpDriveSubsystemInfo,
+}
+
+impl From<&ClassicShipPlayerUnit> for ClassicShipSnapshot {
+    fn from(ship: &ClassicShipPlayerUnit) -> Self {
+        Self {
+            engine: ship.engine().clone(),
+            nebula_collector: ship.nebula_collector().clone(),
+            shot_launcher: ship.shot_launcher().clone(),
+            shot_magazine: ship.shot_magazine().clone(),
+            shot_fabricator: ship.shot_fabricator().clone(),
+            interceptor_launcher: ship.interceptor_launcher().clone(),
+            interceptor_magazine: ship.interceptor_magazine().clone(),
+            interceptor_fabricator: ship.interceptor_fabricator().clone(),
+            railgun: ship.railgun().clone(),
+            main_scanner: ship.main_scanner().clone(),
+            secondary_scanner: ship.secondary_scanner().clone(),
+            jump_drive: ship.jump_drive().clone(),
+        }
+    }
+}
+
+/// The subsystems of a [`ModernShipPlayerUnit`] in a [`UnitSnapshot`], next to the ones in
+/// [`PlayerUnitSnapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModernShipSnapshot {
+    pub nebula_collector: NebulaCollectorSubsystemInfo,
+    pub engines: Vec<ModernShipEngineSubsystemInfo>,
+    pub scanners: Vec<DynamicScannerSubsystemInfo>,
+    pub shot_launchers: Vec<DynamicShotLauncherSubsystemInfo>,
+    pub shot_magazines: Vec<StaticShotMagazineSubsystemInfo>,
+    pub shot_fabricators: Vec<DynamicShotFabricatorSubsystemInfo>,
+    pub interceptor_launchers: Vec<DynamicInterceptorLauncherSubsystemInfo>,
+    pub interceptor_magazines: Vec<StaticInterceptorMagazineSubsystemInfo>,
+    pub interceptor_fabricators: Vec<DynamicInterceptorFabricatorSubsystemInfo>,
+    pub railguns: Vec<ModernRailgunSubsystemInfo>,
+    pub jump_drive: JumpDriveSubsystemInfo,
+}
+
+impl From<&ModernShipPlayerUnit> for ModernShipSnapshot {
+    fn from(ship: &ModernShipPlayerUnit) -> Self {
+        Self {
+            nebula_collector: ship.nebula_collector().clone(),
+            engines: ship.engines().to_vec(),
+            scanners: ship.scanners().to_vec(),
+            shot_launchers: ship.shot_launchers().to_vec(),
+            shot_magazines: ship.shot_magazines().to_vec(),
+            shot_fabricators: ship.shot_fabricators().to_vec(),
+            interceptor_launchers: ship.interceptor_launchers().to_vec(),
+            interceptor_magazines: ship.interceptor_magazines().to_vec(),
+            interceptor_fabricators: ship.interceptor_fabricators().to_vec(),
+            railguns: ship.railguns().to_vec(),
+            jump_drive: ship.jump_drive().clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(details: UnitDetails) -> UnitSnapshot {
+        UnitSnapshot {
+            name: "unit".to_string(),
+            cluster: ClusterId(2),
+            position: Vector::new(100.0, -50.0),
+            movement: Vector::default(),
+            angle: 45.0,
+            radius: 20.0,
+            gravity: 0.5,
+            speed_limit: 0.0,
+            mobility: Mobility::Still,
+            is_masking: true,
+            is_solid: true,
+            can_be_edited: false,
+            full_state_known: true,
+            team: None,
+            steady: None,
+            angular_velocity: None,
+            amount: None,
+            npc_hull: None,
+            player_unit: None,
+            projectile: None,
+            explosion: None,
+            details,
+        }
+    }
+
+    /// Serializes the snapshot, reads it back and checks that nothing got lost on the way.
+    fn assert_round_trip(snapshot: &UnitSnapshot) {
+        let json = serde_json::to_value(snapshot).unwrap();
+        let read = serde_json::from_value::<UnitSnapshot>(json.clone()).unwrap();
+        assert_eq!(read.kind(), snapshot.kind());
+        assert_eq!(serde_json::to_value(&read).unwrap(), json);
+    }
+
+    #[test]
+    fn steady_unit() {
+        let mut sun = snapshot(UnitDetails::Sun {
+            energy: 10.0,
+            ions: 1.0,
+            neutrinos: 0.5,
+            heat: 2.0,
+            drain: 0.25,
+        });
+        sun.mobility = Mobility::Steady;
+        sun.steady = Some(SteadySnapshot {
+            configured_position: Vector::new(100.0, 0.0),
+            orbits: vec![Orbit::new(50.0, 90.0, 400), Orbit::new(5.0, 0.0, -20)],
+        });
+        assert_round_trip(&sun);
+
+        let json = serde_json::to_value(&sun).unwrap();
+        assert_eq!(json["kind"], "Sun");
+        assert_eq!(json["steady"]["orbits"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn player_ship() {
+        let mut ship = snapshot(UnitDetails::ClassicShipPlayerUnit(Box::new(
+            ClassicShipSnapshot {
+                engine: Default::default(),
+                nebula_collector: Default::default(),
+                shot_launcher: Default::default(),
+                shot_magazine: Default::default(),
+                shot_fabricator: Default::default(),
+                interceptor_launcher: Default::default(),
+                interceptor_magazine: Default::default(),
+                interceptor_fabricator: Default::default(),
+                railgun: Default::default(),
+                main_scanner: Default::default(),
+                secondary_scanner: Default::default(),
+                jump_drive: Default::default(),
+            },
+        )));
+        ship.mobility = Mobility::Mobile;
+        ship.movement = Vector::new(1.5, -0.5);
+        ship.team = Some(TeamId(1));
+        ship.angular_velocity = Some(3.0);
+        ship.player_unit = Some(Box::new(PlayerUnitSnapshot {
+            player: PlayerId(7),
+            controllable_info: ControllableInfoId(3),
+            effective_structural_load: 12.0,
+            energy_battery: Default::default(),
+            ion_battery: Default::default(),
+            neutrino_battery: Default::default(),
+            energy_cell: Default::default(),
+            ion_cell: Default::default(),
+            neutrino_cell: Default::default(),
+            hull: Default::default(),
+            shield: Default::default(),
+            armor: Default::default(),
+            repair: Default::default(),
+            cargo: Default::default(),
+            resource_miner: Default::default(),
+        }));
+        assert_round_trip(&ship);
+    }
+
+    #[test]
+    fn unknown() {
+        let unknown = snapshot(UnitDetails::Unknown { id: 0xF0 });
+        assert_round_trip(&unknown);
+
+        let read = serde_json::from_value::<UnitSnapshot>(serde_json::to_value(&unknown).unwrap())
+            .unwrap();
+        assert_eq!(read.kind(), UnitKind::Unknown(0xF0));
+    }
+}
@@ -1,12 +1,13 @@
 use crate::galaxy_hierarchy::{Cluster, ClusterId};
 use crate::network::PacketReader;
 use crate::unit::{
-    AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable, UnitHierarchy,
-    UnitInternal, UnitKind,
+    fmt_unit, AbstractSteadyUnit, SteadyUnit, SteadyUnitInternal, Unit, UnitCastTable,
+    UnitHierarchy, UnitInternal, UnitKind,
 };
 use crate::utils::Atomic;
 use crate::GameError;
 use arc_swap::ArcSwapWeak;
+use std::fmt::{Display, Formatter};
 use std::sync::{Arc, Weak};
 
 /// A worm hole with a visible jump target after full disclosure.
@@ -141,6 +142,26 @@ impl Unit for WormHole {
     }
 }
 
+impl Display for WormHole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_unit(self, f)?;
+        if let Some(cluster) = self.target_cluster().upgrade() {
+            write!(f, " target={:?}", &*cluster.name())?;
+        }
+        if f.alternate() {
+            write!(
+                f,
+                " target_area=({}, {})..({}, {})",
+                self.target_left(),
+                self.target_top(),
+                self.target_right(),
+                self.target_bottom()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl SteadyUnitInternal for WormHole {
     #[inline]
     fn parent(&self) -> &dyn SteadyUnit {
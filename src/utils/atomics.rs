@@ -102,6 +102,23 @@ where
     }
 }
 
+/// Serializes the current value.
+#[cfg(feature = "unit-serde")]
+impl<T: Atomar + serde::Serialize> serde::Serialize for Atomic<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.load().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "unit-serde")]
+impl<'de, T: Atomar + serde::Deserialize<'de>> serde::Deserialize<'de> for Atomic<T> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::from)
+    }
+}
+
 pub trait Atomar {
     type Container;
 
@@ -480,3 +497,25 @@ impl Atomar for Option<u16> {
         }
     }
 }
+
+/// Unlike the other optional values, `None` is stored as `0`, so the default container is `None`.
+impl Atomar for Option<u32> {
+    type Container = AtomicU64;
+
+    fn into_container(self) -> Self::Container {
+        let container = Self::Container::default();
+        self.store(&container, Ordering::Relaxed);
+        container
+    }
+
+    fn store(self, container: &Self::Container, ordering: Ordering) {
+        container.store(self.map_or(0, |value| u64::from(value) + 1), ordering);
+    }
+
+    fn load(container: &Self::Container, ordering: Ordering) -> Self {
+        match container.load(ordering) {
+            0 => None,
+            value => Some((value - 1) as u32),
+        }
+    }
+}
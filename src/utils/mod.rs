@@ -7,6 +7,9 @@ pub use atomics::*;
 mod arc_deref;
 pub use arc_deref::*;
 
+pub(crate) mod spatial_grid;
+pub(crate) use spatial_grid::*;
+
 /// Validates a Flattiverse name against the connector's local name rules.
 pub fn check_name_or_err<S: AsRef<str>>(name: S) -> Result<S, GameError> {
     if check_name(name.as_ref()) {
@@ -50,7 +53,7 @@ pub fn check_message_or_err<S: AsRef<str>>(message: S) -> Result<S, GameError> {
 
 pub fn check_message(message: &str) -> bool {
     #[allow(clippy::if_same_then_else, clippy::len_zero)]
-    if message.len() < 1 || message.len() > 512 {
+    if message.len() < 1 || message.len() > MAX_MESSAGE_LEN {
         false
     } else if message.trim().len() != message.len() {
         false
@@ -85,6 +88,61 @@ pub fn check_message(message: &str) -> bool {
     }
 }
 
+/// The maximum length of a chat message in bytes, see [`check_message`].
+pub const MAX_MESSAGE_LEN: usize = 512;
+
+/// The maximum length of a binary chat message in bytes, see
+/// [`crate::galaxy_hierarchy::Player::chat_binary`].
+pub const MAX_BINARY_MESSAGE_LEN: usize = 2 * MAX_MESSAGE_LEN;
+
+/// Splits an arbitrary text into chat messages which pass [`check_message`]: every line becomes
+/// at least one message, lines longer than [`MAX_MESSAGE_LEN`] are split at whitespace if
+/// possible, unsupported characters are replaced by `?` and empty lines are skipped.
+pub fn split_message(text: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+
+    for line in text.lines() {
+        let mut line = line
+            .chars()
+            .map(|c| {
+                if c == '\t' {
+                    ' '
+                } else if check_message(c.encode_utf8(&mut [0; 4])) || c == ' ' {
+                    c
+                } else {
+                    '?'
+                }
+            })
+            .collect::<String>();
+
+        loop {
+            let trimmed = line.trim();
+            if trimmed.len() <= MAX_MESSAGE_LEN {
+                if !trimmed.is_empty() {
+                    messages.push(trimmed.to_string());
+                }
+                break;
+            }
+
+            let mut end = MAX_MESSAGE_LEN;
+            let start = line.len() - line.trim_start().len();
+            while !line.is_char_boundary(start + end) {
+                end -= 1;
+            }
+            let chunk = &line[start..start + end];
+            let split = chunk
+                .rfind(' ')
+                .filter(|split| *split > 0)
+                .unwrap_or(chunk.len());
+
+            messages.push(chunk[..split].trim_end().to_string());
+            line = line[start + split..].to_string();
+        }
+    }
+
+    messages
+}
+
 pub trait Also {
     fn also(self, f: impl FnOnce(&mut Self)) -> Self
     where
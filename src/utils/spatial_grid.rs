@@ -0,0 +1,695 @@
+use crate::Vector;
+use std::collections::{HashMap, HashSet};
+
+type Cell = (i32, i32);
+
+#[derive(Debug)]
+struct Entry<V> {
+    key: String,
+    position: Vector,
+    radius: f32,
+    /// The covered cells, inclusive. Unused for oversized entries.
+    min: Cell,
+    max: Cell,
+    oversized: bool,
+    value: V,
+}
+
+impl<V> Entry<V> {
+    /// The distance from the point to the circle of the entry, `0.0` if the point is inside and
+    /// infinite for damaged coordinates.
+    #[inline]
+    fn distance_to(&self, point: Vector) -> f32 {
+        let distance = (self.position - point).length() - self.radius;
+        // `f32::max` would turn NaN into `0.0`
+        if distance.is_nan() {
+            f32::INFINITY
+        } else {
+            distance.max(0.0)
+        }
+    }
+}
+
+/// Uniform grid over circles identified by a name, used by
+/// [`crate::galaxy_hierarchy::Cluster`] to answer proximity queries without scanning all units.
+///
+/// An entry is stored in every cell its bounding box touches, so large units like suns are found
+/// from every cell they reach into. All distances are measured to the circle of an entry, not its
+/// center.
+#[derive(Debug)]
+pub struct SpatialGrid<V> {
+    cell_size: f32,
+    slots: Vec<Option<Entry<V>>>,
+    free: Vec<usize>,
+    keys: HashMap<String, usize>,
+    cells: HashMap<Cell, Vec<usize>>,
+    /// Entries spanning too many cells, or with damaged coordinates, checked by every query.
+    oversized: Vec<usize>,
+    /// Inclusive range of the cells which contained entries since the last [`SpatialGrid::clear`].
+    /// It never shrinks, which only makes queries look a bit further than necessary.
+    bounds: Option<(Cell, Cell)>,
+}
+
+impl<V> Default for SpatialGrid<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CELL_SIZE)
+    }
+}
+
+impl<V> SpatialGrid<V> {
+    /// Roughly the scan range of a ship, so most queries only touch a handful of cells.
+    pub const DEFAULT_CELL_SIZE: f32 = 250.0;
+
+    /// Entries spanning more cells per axis are not stored in the cells.
+    const MAX_CELL_SPAN: i64 = 64;
+
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size.is_finite() && cell_size > 0.0,
+            "Invalid cell size {cell_size}"
+        );
+        Self {
+            cell_size,
+            slots: Vec::new(),
+            free: Vec::new(),
+            keys: HashMap::new(),
+            cells: HashMap::new(),
+            oversized: Vec::new(),
+            bounds: None,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    #[inline]
+    fn cell(&self, position: Vector) -> Cell {
+        // `as` saturates, so even damaged positions end up in some (border) cell
+        (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Inserts the entry or moves and updates it, if the key is already present.
+    pub fn insert(&mut self, key: &str, position: Vector, radius: f32, value: V) {
+        let radius = radius.max(0.0);
+        let min = self.cell(Vector::new(position.x - radius, position.y - radius));
+        let max = self.cell(Vector::new(position.x + radius, position.y + radius));
+        let oversized = position.is_damaged()
+            || !radius.is_finite()
+            || i64::from(max.0) - i64::from(min.0) >= Self::MAX_CELL_SPAN
+            || i64::from(max.1) - i64::from(min.1) >= Self::MAX_CELL_SPAN;
+
+        if let Some(&slot) = self.keys.get(key) {
+            let entry = self.slots[slot].as_mut().expect("Indexed slot is occupied");
+            let (old_min, old_max, old_oversized) = (entry.min, entry.max, entry.oversized);
+            entry.position = position;
+            entry.radius = radius;
+            entry.value = value;
+            if (old_min, old_max, old_oversized) != (min, max, oversized) {
+                entry.min = min;
+                entry.max = max;
+                entry.oversized = oversized;
+                self.unlink(slot, old_min, old_max, old_oversized);
+                self.link(slot, min, max, oversized);
+            }
+        } else {
+            let entry = Entry {
+                key: key.to_string(),
+                position,
+                radius,
+                min,
+                max,
+                oversized,
+                value,
+            };
+            let slot = match self.free.pop() {
+                Some(slot) => {
+                    self.slots[slot] = Some(entry);
+                    slot
+                }
+                None => {
+                    self.slots.push(Some(entry));
+                    self.slots.len() - 1
+                }
+            };
+            self.keys.insert(key.to_string(), slot);
+            self.link(slot, min, max, oversized);
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let slot = self.keys.remove(key)?;
+        let entry = self.slots[slot].take().expect("Indexed slot is occupied");
+        self.unlink(slot, entry.min, entry.max, entry.oversized);
+        self.free.push(slot);
+        Some(entry.value)
+    }
+
+    #[cfg_attr(not(feature = "bench-internals"), allow(unused))]
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free.clear();
+        self.keys.clear();
+        self.cells.clear();
+        self.oversized.clear();
+        self.bounds = None;
+    }
+
+    fn link(&mut self, slot: usize, min: Cell, max: Cell, oversized: bool) {
+        if oversized {
+            self.oversized.push(slot);
+            return;
+        }
+        self.bounds = Some(match self.bounds {
+            None => (min, max),
+            Some((bounds_min, bounds_max)) => (
+                (bounds_min.0.min(min.0), bounds_min.1.min(min.1)),
+                (bounds_max.0.max(max.0), bounds_max.1.max(max.1)),
+            ),
+        });
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                self.cells.entry((x, y)).or_default().push(slot);
+            }
+        }
+    }
+
+    fn unlink(&mut self, slot: usize, min: Cell, max: Cell, oversized: bool) {
+        if oversized {
+            self.oversized.retain(|s| *s != slot);
+            return;
+        }
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                if let Some(slots) = self.cells.get_mut(&(x, y)) {
+                    slots.retain(|s| *s != slot);
+                    if slots.is_empty() {
+                        self.cells.remove(&(x, y));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Calls `f` once for every entry whose cells overlap the given cell range.
+    fn visit<'a>(&'a self, min: Cell, max: Cell, mut f: impl FnMut(&'a Entry<V>)) {
+        for slot in &self.oversized {
+            f(self.slots[*slot]
+                .as_ref()
+                .expect("Indexed slot is occupied"));
+        }
+
+        // iterate whichever is smaller: the cells of the range or the occupied cells
+        let range = (i64::from(max.0) - i64::from(min.0) + 1)
+            .saturating_mul(i64::from(max.1) - i64::from(min.1) + 1);
+        if range > self.cells.len() as i64 {
+            for (&cell, slots) in &self.cells {
+                if (min.0..=max.0).contains(&cell.0) && (min.1..=max.1).contains(&cell.1) {
+                    self.visit_cell(cell, slots, min, &mut f);
+                }
+            }
+        } else {
+            for x in min.0..=max.0 {
+                for y in min.1..=max.1 {
+                    if let Some(slots) = self.cells.get(&(x, y)) {
+                        self.visit_cell((x, y), slots, min, &mut f);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_cell<'a>(
+        &'a self,
+        cell: Cell,
+        slots: &[usize],
+        min: Cell,
+        f: &mut impl FnMut(&'a Entry<V>),
+    ) {
+        for slot in slots {
+            let entry = self.slots[*slot]
+                .as_ref()
+                .expect("Indexed slot is occupied");
+            // an entry spanning several cells of the range is only reported from the first one
+            if cell == (entry.min.0.max(min.0), entry.min.1.max(min.1)) {
+                f(entry);
+            }
+        }
+    }
+
+    /// The entries whose circle overlaps the given circle.
+    pub fn within(&self, center: Vector, radius: f32) -> Vec<&V> {
+        let mut result = Vec::new();
+        let min = self.cell(Vector::new(center.x - radius, center.y - radius));
+        let max = self.cell(Vector::new(center.x + radius, center.y + radius));
+        self.visit(min, max, |entry| {
+            if entry.distance_to(center) <= radius {
+                result.push(&entry.value);
+            }
+        });
+        result
+    }
+
+    /// The entries whose circle overlaps the rectangle spanned by the two corners.
+    pub fn in_rect(&self, a: Vector, b: Vector) -> Vec<&V> {
+        let (left, right) = (a.x.min(b.x), a.x.max(b.x));
+        let (top, bottom) = (a.y.min(b.y), a.y.max(b.y));
+        let mut result = Vec::new();
+        let min = self.cell(Vector::new(left, top));
+        let max = self.cell(Vector::new(right, bottom));
+        self.visit(min, max, |entry| {
+            let closest = Vector::new(
+                entry.position.x.clamp(left, right),
+                entry.position.y.clamp(top, bottom),
+            );
+            if (entry.position - closest).length_squared() <= entry.radius * entry.radius {
+                result.push(&entry.value);
+            }
+        });
+        result
+    }
+
+    /// The entry closest to the position which passes the filter, with its distance.
+    #[inline]
+    pub fn nearest(&self, position: Vector, filter: impl FnMut(&V) -> bool) -> Option<(&V, f32)> {
+        self.k_nearest(position, 1, filter).into_iter().next()
+    }
+
+    /// Up to `k` entries closest to the position which pass the filter, with their distances,
+    /// closest first.
+    pub fn k_nearest<'a>(
+        &'a self,
+        position: Vector,
+        k: usize,
+        mut filter: impl FnMut(&V) -> bool,
+    ) -> Vec<(&'a V, f32)> {
+        let mut result: Vec<(&'a V, f32)> = Vec::with_capacity(k.min(self.len()));
+        if k == 0 || self.is_empty() {
+            return result;
+        }
+
+        let mut seen = HashSet::new();
+        let mut consider = |slot: usize, result: &mut Vec<(&'a V, f32)>| {
+            if !seen.insert(slot) {
+                return;
+            }
+            let entry = self.slots[slot].as_ref().expect("Indexed slot is occupied");
+            if !filter(&entry.value) {
+                return;
+            }
+            let distance = entry.distance_to(position);
+            if result.len() < k || distance < result[result.len() - 1].1 {
+                let index = result.partition_point(|(_, d)| *d <= distance);
+                result.insert(index, (&entry.value, distance));
+                result.truncate(k);
+            }
+        };
+
+        for slot in &self.oversized {
+            consider(*slot, &mut result);
+        }
+        let Some((occupied_min, occupied_max)) = self.occupied_bounds() else {
+            return result;
+        };
+
+        // the rings closer than the occupied cells are empty
+        let center = self.cell(position);
+        let first_ring = [
+            i64::from(occupied_min.0) - i64::from(center.0),
+            i64::from(center.0) - i64::from(occupied_max.0),
+            i64::from(occupied_min.1) - i64::from(center.1),
+            i64::from(center.1) - i64::from(occupied_max.1),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default()
+        .max(0);
+
+        for ring in first_ring.. {
+            let min = (i64::from(center.0) - ring, i64::from(center.1) - ring);
+            let max = (i64::from(center.0) + ring, i64::from(center.1) + ring);
+
+            if ring.saturating_mul(8) > self.len() as i64 {
+                // the ring has more cells than there are entries, a scan is cheaper
+                for slot in self.keys.values() {
+                    consider(*slot, &mut result);
+                }
+                break;
+            }
+
+            let in_range = |x: i64, y: i64| -> Option<Cell> {
+                Some((i32::try_from(x).ok()?, i32::try_from(y).ok()?))
+            };
+            let mut visit = |x: i64, y: i64| {
+                if let Some(slots) = in_range(x, y).and_then(|cell| self.cells.get(&cell)) {
+                    for slot in slots {
+                        consider(*slot, &mut result);
+                    }
+                }
+            };
+
+            if ring == 0 {
+                visit(min.0, min.1);
+            } else {
+                for x in min.0..=max.0 {
+                    visit(x, min.1);
+                    visit(x, max.1);
+                }
+                for y in min.1 + 1..max.1 {
+                    visit(min.0, y);
+                    visit(max.0, y);
+                }
+            }
+
+            // all entries not seen yet lie completely outside the rings visited so far
+            let covered = min.0 <= i64::from(occupied_min.0)
+                && min.1 <= i64::from(occupied_min.1)
+                && max.0 >= i64::from(occupied_max.0)
+                && max.1 >= i64::from(occupied_max.1);
+            let unseen_distance = ring as f32 * self.cell_size;
+            if covered || (result.len() == k && result[k - 1].1 <= unseen_distance) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// The first entry hit by the ray which passes the filter, with the distance along the ray.
+    /// An entry containing the origin is hit at distance `0.0`.
+    pub fn raycast<'a>(
+        &'a self,
+        origin: Vector,
+        direction: Vector,
+        max_length: f32,
+        mut filter: impl FnMut(&V) -> bool,
+    ) -> Option<(&'a V, f32)> {
+        let length = direction.length();
+        if origin.is_damaged() || !(length > 0.0 && length.is_finite()) || max_length.is_nan() {
+            return None;
+        }
+        let direction = direction / length;
+
+        let mut seen = HashSet::new();
+        let mut best: Option<(&'a V, f32)> = None;
+        let mut check = |slot: usize, best: &mut Option<(&'a V, f32)>| {
+            if !seen.insert(slot) {
+                return;
+            }
+            let entry = self.slots[slot].as_ref().expect("Indexed slot is occupied");
+            if let Some(distance) = ray_circle(origin, direction, entry.position, entry.radius) {
+                if distance <= max_length
+                    && best.is_none_or(|(_, best)| distance < best)
+                    && filter(&entry.value)
+                {
+                    *best = Some((&entry.value, distance));
+                }
+            }
+        };
+
+        for slot in &self.oversized {
+            check(*slot, &mut best);
+        }
+        let Some((occupied_min, occupied_max)) = self.occupied_bounds() else {
+            return best;
+        };
+
+        // the ray does not need to be followed beyond the occupied cells
+        let bounds_min = Vector::new(
+            occupied_min.0 as f32 * self.cell_size,
+            occupied_min.1 as f32 * self.cell_size,
+        );
+        let bounds_max = Vector::new(
+            (occupied_max.0 as f32 + 1.0) * self.cell_size,
+            (occupied_max.1 as f32 + 1.0) * self.cell_size,
+        );
+        let Some((enter, exit)) = ray_rect(origin, direction, bounds_min, bounds_max) else {
+            return best;
+        };
+        let limit = exit.min(max_length);
+        if enter > limit {
+            return best;
+        }
+
+        // walks the cells along the ray, see Amanatides & Woo, "A Fast Voxel Traversal Algorithm",
+        // starting where the ray enters the occupied cells
+        let start = origin + direction * enter;
+        let mut cell = self.cell(start);
+        let axis = |start: f32, direction: f32, cell: i32| -> (i32, f32, f32) {
+            if direction > 0.0 {
+                let next = (cell as f32 + 1.0) * self.cell_size;
+                (
+                    1,
+                    enter + (next - start) / direction,
+                    self.cell_size / direction,
+                )
+            } else if direction < 0.0 {
+                let next = cell as f32 * self.cell_size;
+                (
+                    -1,
+                    enter + (next - start) / direction,
+                    -self.cell_size / direction,
+                )
+            } else {
+                (0, f32::INFINITY, f32::INFINITY)
+            }
+        };
+        let (step_x, mut next_x, delta_x) = axis(start.x, direction.x, cell.0);
+        let (step_y, mut next_y, delta_y) = axis(start.y, direction.y, cell.1);
+
+        loop {
+            for slot in self.cells.get(&cell).into_iter().flatten() {
+                check(*slot, &mut best);
+            }
+
+            let exit = next_x.min(next_y);
+            if exit > limit || best.is_some_and(|(_, distance)| distance <= exit) {
+                break;
+            }
+
+            if next_x < next_y {
+                cell.0 = cell.0.saturating_add(step_x);
+                next_x += delta_x;
+            } else {
+                cell.1 = cell.1.saturating_add(step_y);
+                next_y += delta_y;
+            }
+        }
+
+        best
+    }
+
+    /// The inclusive range of the cells containing entries, `None` if there are none.
+    #[inline]
+    fn occupied_bounds(&self) -> Option<(Cell, Cell)> {
+        self.bounds.filter(|_| !self.cells.is_empty())
+    }
+
+    /// All entries in no particular order.
+    #[inline]
+    #[cfg_attr(not(feature = "bench-internals"), allow(unused))]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.slots
+            .iter()
+            .flatten()
+            .map(|entry| (entry.key.as_str(), &entry.value))
+    }
+}
+
+/// The distance along the normalized direction at which the ray enters the circle, `0.0` if the
+/// origin is inside.
+fn ray_circle(origin: Vector, direction: Vector, center: Vector, radius: f32) -> Option<f32> {
+    let offset = origin - center;
+    let c = offset.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let b = offset.x * direction.x + offset.y * direction.y;
+    let discriminant = b * b - c;
+    if b > 0.0 || discriminant < 0.0 {
+        None
+    } else {
+        Some(-b - discriminant.sqrt())
+    }
+}
+
+/// The distances along the normalized direction at which the ray enters and leaves the rectangle,
+/// `None` if it misses the rectangle.
+fn ray_rect(origin: Vector, direction: Vector, min: Vector, max: Vector) -> Option<(f32, f32)> {
+    let mut enter = 0.0_f32;
+    let mut exit = f32::INFINITY;
+    for (origin, direction, min, max) in [
+        (origin.x, direction.x, min.x, max.x),
+        (origin.y, direction.y, min.y, max.y),
+    ] {
+        if direction == 0.0 {
+            if origin < min || origin > max {
+                return None;
+            }
+        } else {
+            let a = (min - origin) / direction;
+            let b = (max - origin) / direction;
+            enter = enter.max(a.min(b));
+            exit = exit.min(a.max(b));
+        }
+    }
+    (enter <= exit).then_some((enter, exit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two small units next to each other at the origin and a larger one further away, on a grid
+    /// with cells of 100.
+    fn grid() -> SpatialGrid<u32> {
+        let mut grid = SpatialGrid::new(100.0);
+        grid.insert("a", Vector::new(0.0, 0.0), 10.0, 1);
+        grid.insert("b", Vector::new(150.0, 0.0), 10.0, 2);
+        grid.insert("c", Vector::new(1000.0, 1000.0), 50.0, 3);
+        grid
+    }
+
+    fn sorted(mut values: Vec<&u32>) -> Vec<u32> {
+        values.sort();
+        values.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn within() {
+        let grid = grid();
+        assert_eq!(sorted(grid.within(Vector::new(0.0, 0.0), 5.0)), [1]);
+        // measured to the circle of b, not its center
+        assert_eq!(sorted(grid.within(Vector::new(0.0, 0.0), 140.0)), [1, 2]);
+        assert_eq!(sorted(grid.within(Vector::new(0.0, 0.0), 139.0)), [1]);
+        assert!(grid.within(Vector::new(500.0, 500.0), 10.0).is_empty());
+    }
+
+    #[test]
+    fn in_rect() {
+        let grid = grid();
+        assert_eq!(
+            sorted(grid.in_rect(Vector::new(145.0, 5.0), Vector::new(-5.0, -5.0))),
+            [1, 2]
+        );
+        // the corner of the rectangle is outside the circle of a
+        assert!(grid
+            .in_rect(Vector::new(10.0, 10.0), Vector::new(100.0, 100.0))
+            .is_empty());
+        assert_eq!(
+            sorted(grid.in_rect(Vector::new(0.0, 0.0), Vector::new(2000.0, 2000.0))),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn k_nearest() {
+        let grid = grid();
+        let position = Vector::new(140.0, 0.0);
+        assert_eq!(
+            grid.k_nearest(position, 2, |_| true),
+            [(&2, 0.0), (&1, 130.0)]
+        );
+        assert_eq!(grid.k_nearest(position, 5, |_| true).len(), 3);
+        assert!(grid.k_nearest(position, 0, |_| true).is_empty());
+
+        assert_eq!(grid.nearest(position, |_| true), Some((&2, 0.0)));
+        assert_eq!(
+            grid.nearest(position, |value| *value != 2),
+            Some((&1, 130.0))
+        );
+        assert_eq!(grid.nearest(position, |_| false), None);
+    }
+
+    #[test]
+    fn raycast() {
+        let grid = grid();
+        let origin = Vector::new(-100.0, 0.0);
+        let direction = Vector::new(2.0, 0.0);
+        assert_eq!(
+            grid.raycast(origin, direction, 1000.0, |_| true),
+            Some((&1, 90.0))
+        );
+        assert_eq!(
+            grid.raycast(origin, direction, 1000.0, |value| *value != 1),
+            Some((&2, 240.0))
+        );
+        assert_eq!(grid.raycast(origin, direction, 50.0, |_| true), None);
+        assert_eq!(
+            grid.raycast(Vector::new(0.0, 0.0), direction, 50.0, |_| true),
+            Some((&1, 0.0))
+        );
+        assert_eq!(
+            grid.raycast(origin, Vector::default(), 1000.0, |_| true),
+            None
+        );
+    }
+
+    #[test]
+    fn oversized() {
+        let mut grid = SpatialGrid::default();
+        // spans 80 cells of 250 per axis
+        grid.insert("sun", Vector::new(0.0, 0.0), 10_000.0, 1);
+        grid.insert("damaged", Vector::new(f32::NAN, 0.0), 10.0, 2);
+        assert_eq!(grid.len(), 2);
+
+        assert_eq!(sorted(grid.within(Vector::new(9_000.0, 0.0), 10.0)), [1]);
+        assert_eq!(
+            grid.k_nearest(Vector::new(0.0, 0.0), 2, |_| true),
+            [(&1, 0.0), (&2, f32::INFINITY)]
+        );
+        assert_eq!(
+            grid.raycast(
+                Vector::new(-20_000.0, 0.0),
+                Vector::new(1.0, 0.0),
+                20_000.0,
+                |_| true
+            ),
+            Some((&1, 10_000.0))
+        );
+
+        // shrinking moves the sun back into the cells
+        grid.insert("sun", Vector::new(0.0, 0.0), 100.0, 1);
+        assert!(grid.within(Vector::new(9_000.0, 0.0), 10.0).is_empty());
+        assert_eq!(sorted(grid.within(Vector::new(150.0, 0.0), 60.0)), [1]);
+
+        assert_eq!(grid.remove("damaged"), Some(2));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn move_and_remove() {
+        let mut grid = grid();
+        grid.insert("a", Vector::new(5000.0, 5000.0), 10.0, 4);
+        assert_eq!(grid.len(), 3);
+        assert!(grid.within(Vector::new(0.0, 0.0), 20.0).is_empty());
+        assert_eq!(sorted(grid.within(Vector::new(5000.0, 5000.0), 20.0)), [4]);
+
+        assert_eq!(grid.remove("b"), Some(2));
+        assert_eq!(grid.remove("b"), None);
+        assert_eq!(
+            grid.nearest(Vector::new(150.0, 0.0), |_| true).unwrap().0,
+            &3
+        );
+
+        // the slot of b is reused
+        grid.insert("d", Vector::new(150.0, 0.0), 10.0, 5);
+        let mut entries = grid.iter().collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(entries, [("a", &4), ("c", &3), ("d", &5)]);
+
+        grid.clear();
+        assert!(grid.is_empty());
+        assert!(grid.within(Vector::new(150.0, 0.0), 20.0).is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::network::{PacketReader, PacketWriter};
+use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
 /// Mutable two-dimensional vector with degree-based angle helpers.
@@ -142,6 +143,38 @@ impl Vector {
     }
 }
 
+impl Display for Vector {
+    /// `(x, y)` with one decimal place, unless another precision is requested.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(1);
+        write!(f, "({:.*}, {:.*})", precision, self.x, precision, self.y)
+    }
+}
+
+impl serde::Serialize for Vector {
+    /// Serializes the components only, the cached angle is an implementation detail.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vector", 2)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Vector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Components {
+            x: f32,
+            y: f32,
+        }
+
+        let Components { x, y } = Components::deserialize(deserializer)?;
+        Ok(Vector::new(x, y))
+    }
+}
+
 impl Add for Vector {
     type Output = Vector;
 